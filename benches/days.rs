@@ -0,0 +1,88 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Benchmarks of the parser and of every variant of each day.
+//!
+//! Runs on the puzzle input when present, or else on the example.
+//! Filter with `cargo bench --bench days -- day05`.
+
+use std::hint::black_box;
+
+use adventofcode::config::config;
+use adventofcode::variant::Params;
+use adventofcode::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Parser of each day, consuming lazy parsers entirely.
+fn parser(day: u8) -> Option<fn(&str)> {
+    let parser: fn(&str) = match day {
+        1 => |input| drop(black_box(day01::parse_input(input))),
+        4 => |input| input.lines().map(day04::parse_line).for_each(drop),
+        5 => |input| {
+            let (stacks, instructions) = day05::parse_input(input);
+            black_box(stacks);
+            instructions.for_each(drop);
+        },
+        7 => |input| drop(black_box(day07::parse_input(input))),
+        8 => |input| drop(black_box(input.parse::<day08::Map>())),
+        9 => |input| day09::parse_moves(input).for_each(drop),
+        10 => |input| day10::parse_intstruction(input).for_each(drop),
+        11 => |input| drop(black_box(day11::parse_monkeys(input))),
+        12 => |input| drop(black_box(day12::parse_input(input))),
+        13 => |input| day13::parse_input(input).for_each(drop),
+        14 => |input| drop(black_box(day14::parse_input(input))),
+        15 => |input| day15::parse_input(input).for_each(drop),
+        16 => |input| drop(black_box(day16::parse_input(input))),
+        17 => |input| day17::parse_input(input).for_each(drop),
+        18 => |input| day18::parse_input(input).for_each(drop),
+        _ => return None,
+    };
+    Some(parser)
+}
+
+/// Parameters of the parts of `day` sized for its example rather than for its input.
+fn example_params(day: u8) -> Params {
+    match day {
+        15 => Params::from_iter([("target_line", "10"), ("max_coordinate", "20")]),
+        _ => Params::default(),
+    }
+}
+
+fn bench_days(c: &mut Criterion) {
+    for day in 1..=25 {
+        let Some(variants) = adventofcode::variants(day) else {
+            continue;
+        };
+        let (input, params) = match try_read_file("inputs", day) {
+            Some(input) => (input, Params::default()),
+            None => match try_read_file("examples", day) {
+                Some(input) => (input, example_params(day)),
+                None => continue,
+            },
+        };
+
+        let mut group = c.benchmark_group(format!("day{day:02}"));
+        if let Some(parser) = parser(day) {
+            group.bench_function("parse", |b| b.iter(|| parser(black_box(&input))));
+        }
+        // unsolved parts have nothing to measure.
+        for variant in variants
+            .iter()
+            .filter(|variant| variant.solve_with(&input, &params).is_some())
+        {
+            let id = format!("part{}/{}", variant.part, variant.name);
+            group.bench_function(id, |b| {
+                b.iter(|| variant.solve_with(black_box(&input), &params))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(config().bench.iterations.max(10));
+    targets = bench_days
+}
+criterion_main!(benches);
@@ -0,0 +1,9 @@
+#![no_main]
+
+use adventofcode::day14;
+use libfuzzer_sys::fuzz_target;
+
+// `parse_input` draws the paths, which is only bounded by the coordinates.
+fuzz_target!(|input: &str| {
+    let _ = day14::parse_paths(input);
+});
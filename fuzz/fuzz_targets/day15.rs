@@ -0,0 +1,8 @@
+#![no_main]
+
+use adventofcode::day15;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    day15::parse_input(input).for_each(drop);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use adventofcode::day16;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = day16::parse_input(input);
+});
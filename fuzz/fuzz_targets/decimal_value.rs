@@ -0,0 +1,12 @@
+#![no_main]
+
+use adventofcode::helpers::parser::decimal_value;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = decimal_value::<u8>(input);
+    let _ = decimal_value::<u32>(input);
+    let _ = decimal_value::<i32>(input);
+    let _ = decimal_value::<usize>(input);
+    let _ = decimal_value::<i64>(input);
+});
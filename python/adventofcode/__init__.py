@@ -0,0 +1,10 @@
+"""Solutions of Advent of Code, see `python/src/lib.rs`."""
+
+import sys
+
+from . import _adventofcode
+from ._adventofcode import *  # noqa: F403
+
+# the days are submodules of the extension, which are importable once registered.
+for _day in ("day01", "day05", "day07", "day15", "day16", "day18"):
+    sys.modules[f"{__name__}.{_day}"] = getattr(_adventofcode, _day)
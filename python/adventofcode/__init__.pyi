@@ -0,0 +1,25 @@
+"""Solutions of Advent of Code, see `python/src/lib.rs`."""
+
+from typing import List, Optional, Union
+
+from . import day01 as day01
+from . import day05 as day05
+from . import day07 as day07
+from . import day15 as day15
+from . import day16 as day16
+from . import day18 as day18
+
+Answer = Union[int, str]
+
+class Solution:
+    day: int
+    part: int
+    variant: str
+    elapsed: float
+    """Seconds spent solving the part."""
+    answer: Optional[Answer]
+    """`None` if the part is not solved."""
+
+def solve(day: int, part: int, input: str) -> Solution: ...
+def solve_variants(day: int, part: int, input: str) -> List[Solution]: ...
+def read_input(day: int, folder: str = "inputs") -> Optional[str]: ...
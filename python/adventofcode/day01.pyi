@@ -0,0 +1,5 @@
+"""Parsed models of day 1."""
+
+from typing import List
+
+def calories(input: str) -> List[List[int]]: ...
@@ -0,0 +1,5 @@
+"""Parsed models of day 5."""
+
+from typing import List
+
+def stacks(input: str) -> List[str]: ...
@@ -0,0 +1,5 @@
+"""Parsed models of day 7."""
+
+from typing import Dict
+
+def dir_sizes(input: str) -> Dict[str, int]: ...
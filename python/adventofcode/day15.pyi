@@ -0,0 +1,5 @@
+"""Parsed models of day 15."""
+
+from typing import List, Tuple
+
+def sensors(input: str) -> List[Tuple[Tuple[int, int], Tuple[int, int]]]: ...
@@ -0,0 +1,5 @@
+"""Parsed models of day 16."""
+
+from typing import Dict, List, Tuple
+
+def valves(input: str) -> Dict[str, Tuple[int, List[str]]]: ...
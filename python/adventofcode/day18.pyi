@@ -0,0 +1,5 @@
+"""Parsed models of day 18."""
+
+from typing import List, Tuple
+
+def cubes(input: str) -> List[Tuple[int, int, int]]: ...
@@ -0,0 +1,195 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Python bindings of the solutions, see `adventofcode/__init__.pyi` for the Python API.
+
+use std::collections::HashMap;
+
+use adventofcode::variant::{Answer, Variant};
+use adventofcode::{day01, day05, day07, day15, day16, day18};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyCFunction;
+
+/// Answer of a variant of a part, with the time it took.
+#[pyclass(frozen, get_all)]
+struct Solution {
+    day: u8,
+    part: u8,
+    variant: &'static str,
+    /// Seconds spent solving the part.
+    elapsed: f64,
+    answer: Option<PyAnswer>,
+}
+
+#[derive(Clone, IntoPyObject)]
+enum PyAnswer {
+    Number(i64),
+    Text(String),
+}
+
+impl From<Answer> for PyAnswer {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Number(n) => PyAnswer::Number(n),
+            Answer::Text(text) => PyAnswer::Text(text),
+        }
+    }
+}
+
+#[pymethods]
+impl Solution {
+    fn __repr__(&self) -> String {
+        let answer = match &self.answer {
+            Some(PyAnswer::Number(n)) => n.to_string(),
+            Some(PyAnswer::Text(text)) => format!("{text:?}"),
+            None => "None".to_string(),
+        };
+        format!(
+            "Solution(day={}, part={}, variant={:?}, answer={answer}, elapsed={})",
+            self.day, self.part, self.variant, self.elapsed
+        )
+    }
+}
+
+fn variants(day: u8, part: u8) -> PyResult<Vec<Variant>> {
+    let variants: Vec<Variant> = adventofcode::variants(day)
+        .ok_or_else(|| PyValueError::new_err(format!("day {day} is not solved")))?
+        .into_iter()
+        .filter(|variant| variant.part == part)
+        .collect();
+    if variants.is_empty() {
+        return Err(PyValueError::new_err(format!(
+            "day {day} has no part {part}"
+        )));
+    }
+    Ok(variants)
+}
+
+fn run(py: Python<'_>, day: u8, variant: &Variant, input: &str) -> Solution {
+    let (answer, elapsed) = py.allow_threads(|| variant.timed(input));
+    Solution {
+        day,
+        part: variant.part,
+        variant: variant.name,
+        elapsed: elapsed.as_secs_f64(),
+        answer: answer.map(Into::into),
+    }
+}
+
+/// Solves `part` of `day` with its first variant.
+#[pyfunction]
+fn solve(py: Python<'_>, day: u8, part: u8, input: &str) -> PyResult<Solution> {
+    let variants = variants(day, part)?;
+    Ok(run(py, day, &variants[0], input))
+}
+
+/// Solves `part` of `day` with each of its variants.
+#[pyfunction]
+fn solve_variants(py: Python<'_>, day: u8, part: u8, input: &str) -> PyResult<Vec<Solution>> {
+    let variants = variants(day, part)?;
+    Ok(variants
+        .iter()
+        .map(|variant| run(py, day, variant, input))
+        .collect())
+}
+
+/// Reads the puzzle input, or the example, of `day` from the current directory.
+#[pyfunction]
+#[pyo3(signature = (day, folder = "inputs"))]
+fn read_input(day: u8, folder: &str) -> Option<String> {
+    adventofcode::try_read_file(folder, day)
+}
+
+fn parse_error(e: impl std::fmt::Debug) -> PyErr {
+    PyValueError::new_err(format!("invalid input: {e:?}"))
+}
+
+/// Items carried by each elf.
+#[pyfunction]
+fn calories(input: &str) -> Vec<Vec<u32>> {
+    day01::parse_input(input)
+}
+
+/// Crates of each stack, from bottom to top.
+#[pyfunction]
+fn stacks(input: &str) -> Vec<String> {
+    let (stacks, _) = day05::parse_input(input);
+    stacks
+        .into_iter()
+        .map(|stack| String::from_utf8_lossy(&stack.crates).into_owned())
+        .collect()
+}
+
+/// Total size of each directory, by path.
+#[pyfunction]
+fn dir_sizes(input: &str) -> HashMap<String, u32> {
+    day07::parse_input(input)
+}
+
+type Position = (i32, i32);
+
+/// Position of each sensor and of its closest beacon.
+#[pyfunction]
+fn sensors(input: &str) -> PyResult<Vec<(Position, Position)>> {
+    day15::parse_input(input)
+        .map(|sensor| {
+            let day15::Sensor {
+                position: s,
+                nearest_beacon: b,
+            } = sensor.map_err(parse_error)?;
+            Ok(((s.x, s.y), (b.x, b.y)))
+        })
+        .collect()
+}
+
+/// Flow rate and neighbors of each valve, by name.
+#[pyfunction]
+fn valves(input: &str) -> PyResult<HashMap<String, (u32, Vec<String>)>> {
+    let valves = day16::parse_input(input).map_err(parse_error)?;
+    Ok(valves
+        .into_iter()
+        .map(|(name, valve)| (name, (valve.flow_rate, valve.neighbor_valves)))
+        .collect())
+}
+
+/// Position of each cube.
+#[pyfunction]
+fn cubes(input: &str) -> PyResult<Vec<(u32, u32, u32)>> {
+    day18::parse_input(input)
+        .map(|cube| {
+            let cube = cube.map_err(parse_error)?;
+            Ok((cube.x, cube.y, cube.z))
+        })
+        .collect()
+}
+
+fn add_day(
+    parent: &Bound<'_, PyModule>,
+    name: &str,
+    model: Bound<'_, PyCFunction>,
+) -> PyResult<()> {
+    let module = PyModule::new(parent.py(), name)?;
+    module.add_function(model)?;
+    parent.add_submodule(&module)
+}
+
+#[pymodule]
+#[pyo3(name = "_adventofcode")]
+fn adventofcode_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add_class::<Solution>()?;
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_variants, m)?)?;
+    m.add_function(wrap_pyfunction!(read_input, m)?)?;
+
+    // parsed models of the days, as Python values.
+    add_day(m, "day01", wrap_pyfunction!(calories, py)?)?;
+    add_day(m, "day05", wrap_pyfunction!(stacks, py)?)?;
+    add_day(m, "day07", wrap_pyfunction!(dir_sizes, py)?)?;
+    add_day(m, "day15", wrap_pyfunction!(sensors, py)?)?;
+    add_day(m, "day16", wrap_pyfunction!(valves, py)?)?;
+    add_day(m, "day18", wrap_pyfunction!(cubes, py)?)?;
+    Ok(())
+}
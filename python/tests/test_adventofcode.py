@@ -0,0 +1,70 @@
+"""Tests of the bindings, run with `python -m unittest discover python/tests` after `maturin develop`."""
+
+import os
+import unittest
+from pathlib import Path
+
+import adventofcode
+
+# the examples are read relative to the root of the repository.
+os.chdir(Path(__file__).resolve().parents[2])
+
+
+class TestSolve(unittest.TestCase):
+    def test_number_answer(self):
+        solution = adventofcode.solve(1, 1, adventofcode.read_input(1, "examples"))
+        self.assertEqual(solution.answer, 24000)
+        self.assertEqual((solution.day, solution.part, solution.variant), (1, 1, "part_one"))
+        self.assertGreaterEqual(solution.elapsed, 0.0)
+
+    def test_text_answer(self):
+        solution = adventofcode.solve(5, 2, adventofcode.read_input(5, "examples"))
+        self.assertEqual(solution.answer, "MCD")
+
+    def test_unsolved_part(self):
+        solution = adventofcode.solve(16, 2, adventofcode.read_input(16, "examples"))
+        self.assertIsNone(solution.answer)
+
+    def test_variants(self):
+        solutions = adventofcode.solve_variants(2, 1, adventofcode.read_input(2, "examples"))
+        self.assertEqual(len(solutions), 3)
+        self.assertEqual({solution.answer for solution in solutions}, {15})
+
+    def test_unknown_day(self):
+        with self.assertRaises(ValueError):
+            adventofcode.solve(25, 1, "")
+        with self.assertRaises(ValueError):
+            adventofcode.solve(1, 3, "")
+
+    def test_missing_input(self):
+        self.assertIsNone(adventofcode.read_input(25, "examples"))
+
+
+class TestModels(unittest.TestCase):
+    def test_dir_sizes(self):
+        sizes = adventofcode.day07.dir_sizes(adventofcode.read_input(7, "examples"))
+        self.assertEqual(sizes["/"], 48381165)
+        self.assertEqual(sizes["//a/e"], 584)
+
+    def test_stacks(self):
+        stacks = adventofcode.day05.stacks(adventofcode.read_input(5, "examples"))
+        self.assertEqual(stacks, ["ZN", "MCD", "P"])
+
+    def test_valves(self):
+        valves = adventofcode.day16.valves(adventofcode.read_input(16, "examples"))
+        self.assertEqual(valves["AA"], (0, ["DD", "II", "BB"]))
+
+    def test_submodules(self):
+        import adventofcode.day18
+        from adventofcode.day07 import dir_sizes
+
+        self.assertIs(dir_sizes, adventofcode.day07.dir_sizes)
+        self.assertEqual(adventofcode.day18.cubes("1,2,3"), [(1, 2, 3)])
+
+    def test_invalid_input(self):
+        with self.assertRaises(ValueError):
+            adventofcode.day18.cubes("1,2")
+
+
+if __name__ == "__main__":
+    unittest.main()
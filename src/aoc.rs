@@ -0,0 +1,239 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Helpers shared by the commands that talk to adventofcode.com.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fmt, fs};
+
+const USER_AGENT: &str = "github.com/tguichaoua/advent-of-code-2022-rust";
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Puzzles unlock at midnight EST (UTC-5).
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// Returns the instant at which the puzzle of `day` unlocks, if `year` is not before 1970.
+///
+/// ```
+/// # use adventofcode::aoc::unlock_time;
+/// # use std::time::{Duration, UNIX_EPOCH};
+/// // 2022-12-01T05:00:00Z
+/// assert_eq!(unlock_time(2022, 1), Some(UNIX_EPOCH + Duration::from_secs(1669870800)));
+/// assert_eq!(unlock_time(0, 1), None);
+/// ```
+pub fn unlock_time(year: i32, day: u8) -> Option<SystemTime> {
+    let days = u64::try_from(days_from_civil(year, 12, day as u32)).ok()?;
+    let seconds = days * SECONDS_PER_DAY + UNLOCK_HOUR_UTC * 60 * 60;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+/// Returns the year of the latest event that started at `now`.
+///
+/// Mirrors the default of aoc-cli: before December, the latest event is last year's.
+pub fn current_event_year(now: SystemTime) -> i32 {
+    let seconds = now
+        .duration_since(UNIX_EPOCH)
+        .expect("time went backwards")
+        .as_secs();
+    let (year, month, _) = civil_from_days((seconds / SECONDS_PER_DAY) as i64);
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Number of days since 1970-01-01.
+/// See: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year } as i64;
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Inverse of [`days_from_civil`].
+/// See: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = (yoe + era * 400) as i32 + (month <= 2) as i32;
+    (year, month, day)
+}
+
+/// Reads the session cookie the same way aoc-cli does: from the
+/// `ADVENT_OF_CODE_SESSION` environment variable, or else from the configured session file,
+/// `~/.adventofcode.session` by default.
+pub fn session() -> Option<String> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Some(session.trim().to_string());
+    }
+    let path = match crate::config::config().session_file() {
+        Some(path) => path,
+        None => {
+            let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+            std::path::Path::new(&home).join(".adventofcode.session")
+        }
+    };
+    let session = fs::read_to_string(path);
+    session.ok().map(|session| session.trim().to_string())
+}
+
+fn get(url: &str) -> Result<String, Box<ureq::Error>> {
+    let mut request = ureq::get(url).set("User-Agent", USER_AGENT);
+    if let Some(session) = session() {
+        request = request.set("Cookie", &format!("session={session}"));
+    }
+    let response = request.call()?;
+    Ok(response.into_string().map_err(ureq::Error::from)?)
+}
+
+/// Fetches the HTML of the puzzle page of `day`, which contains the description
+/// of each revealed part.
+pub fn fetch_puzzle(year: i32, day: u8) -> Result<String, String> {
+    let url = format!("https://adventofcode.com/{year}/day/{day}");
+    match get(&url) {
+        Ok(html) => Ok(html),
+        Err(e) if matches!(*e, ureq::Error::Status(404, _)) => {
+            Err(format!("day {day} of {year} is not unlocked yet"))
+        }
+        Err(e) => Err(format!("could not fetch {url}: {e}")),
+    }
+}
+
+/// Fetches the JSON of the private leaderboard `id`.
+pub fn fetch_leaderboard(year: i32, id: u64) -> Result<String, String> {
+    let url = format!("https://adventofcode.com/{year}/leaderboard/private/view/{id}.json");
+    get(&url).map_err(|e| format!("could not fetch {url}: {e}"))
+}
+
+/// Formats `duration` as `[<days>d ]HH:MM:SS`.
+///
+/// ```
+/// # use adventofcode::aoc::format_duration;
+/// # use std::time::Duration;
+/// assert_eq!(format_duration(Duration::from_secs(3723)), "01:02:03");
+/// assert_eq!(format_duration(Duration::from_secs(90000)), "1d 01:00:00");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    match seconds / SECONDS_PER_DAY {
+        0 => time,
+        days => format!("{days}d {time}"),
+    }
+}
+
+/// Reasons for which a downloaded file is not a puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputError {
+    Empty,
+    NotLoggedIn,
+    NotUnlocked,
+    Html,
+}
+
+impl InputError {
+    /// Whether downloading again later may succeed.
+    pub fn is_retryable(self) -> bool {
+        !matches!(self, InputError::NotLoggedIn)
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            InputError::Empty => "the downloaded input is empty",
+            InputError::NotLoggedIn => "adventofcode.com asks to log in, check your session cookie",
+            InputError::NotUnlocked => "the puzzle is not unlocked yet",
+            InputError::Html => "adventofcode.com returned an HTML page instead of an input",
+        };
+        f.write_str(message)
+    }
+}
+
+/// Checks that `content` looks like a puzzle input and not like an error
+/// message from adventofcode.com.
+pub fn check_input(content: &str) -> Result<(), InputError> {
+    let trimmed = content.trim_start();
+    if trimmed.is_empty() {
+        return Err(InputError::Empty);
+    }
+    if trimmed.starts_with("Puzzle inputs differ by user.  Please log in") {
+        return Err(InputError::NotLoggedIn);
+    }
+    if trimmed.starts_with("Please don't repeatedly request this endpoint before it unlocks!") {
+        return Err(InputError::NotUnlocked);
+    }
+    let head = trimmed
+        .get(..trimmed.len().min(64))
+        .unwrap_or(trimmed)
+        .to_ascii_lowercase();
+    if head.starts_with("<!doctype html") || head.starts_with("<html") {
+        return Err(InputError::Html);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_conversions() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2022, 12, 18), 19344);
+        assert_eq!(civil_from_days(19344), (2022, 12, 18));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn test_current_event_year() {
+        let at = |year, month, day| {
+            UNIX_EPOCH + Duration::from_secs(days_from_civil(year, month, day) as u64 * 86400)
+        };
+        assert_eq!(current_event_year(at(2022, 12, 18)), 2022);
+        assert_eq!(current_event_year(at(2023, 1, 3)), 2022);
+        assert_eq!(current_event_year(at(2023, 11, 30)), 2022);
+    }
+
+    #[test]
+    fn test_check_input() {
+        assert_eq!(check_input("1000\n2000\n"), Ok(()));
+        assert_eq!(check_input("  \n"), Err(InputError::Empty));
+        assert_eq!(
+            check_input("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err(InputError::NotLoggedIn)
+        );
+        assert_eq!(
+            check_input("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"),
+            Err(InputError::NotUnlocked)
+        );
+        assert_eq!(
+            check_input(
+                "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<title>404 Not Found</title>"
+            ),
+            Err(InputError::Html)
+        );
+        assert_eq!(
+            check_input("<html><body>500 Internal Server Error</body></html>"),
+            Err(InputError::Html)
+        );
+    }
+}
@@ -0,0 +1,272 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Runs the solutions over a directory of inputs, such as the inputs of other accounts
+//! or generated ones, with `cargo all --inputs-dir DIR`.
+//!
+//! Inputs are named after their day, like `NN.txt` or `NN_NAME.txt`, possibly in
+//! subdirectories, and their answers may be given next to them in `NN[_NAME].answers`.
+
+use std::any::Any;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::variant::Variant;
+use crate::{minimize, puzzle};
+
+/// Runs slower than this factor of the median of the part are outliers.
+pub const OUTLIER_FACTOR: f64 = 3.0;
+/// Runs faster than this are never outliers.
+pub const OUTLIER_MIN: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub day: u8,
+    pub path: PathBuf,
+}
+
+impl Input {
+    /// Answers of the sidecar file of the input, if there is one.
+    pub fn answers(&self) -> Vec<(u8, String)> {
+        fs::read_to_string(self.path.with_extension("answers"))
+            .map(|content| puzzle::parse_answers(&content))
+            .unwrap_or_default()
+    }
+}
+
+/// Inputs found in `dir` and its subdirectories, by day and path.
+pub fn find_inputs(dir: &Path) -> io::Result<Vec<Input>> {
+    let mut inputs = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            if path.extension().is_none_or(|extension| extension != "txt") {
+                continue;
+            }
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            let day = stem.get(..2).and_then(|day| day.parse().ok());
+            let rest = stem.get(2..).unwrap_or_default();
+            if let Some(day) = day.filter(|_| rest.is_empty() || rest.starts_with('_')) {
+                inputs.push(Input { day, path });
+            }
+        }
+    }
+    inputs.sort_by(|a, b| (a.day, &a.path).cmp(&(b.day, &b.path)));
+    Ok(inputs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    NotSolved,
+    Panicked(String),
+}
+
+/// Outcome of a variant on an input.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub part: u8,
+    pub variant: &'static str,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    pub expected: Option<String>,
+}
+
+impl Run {
+    /// Whether the variant panicked or answered other than expected.
+    ///
+    /// Multi-line answers draw letters which are not checked.
+    pub fn failed(&self) -> bool {
+        match (&self.outcome, &self.expected) {
+            (Outcome::Panicked(_), _) => true,
+            (Outcome::Solved(answer), Some(expected)) => {
+                !answer.contains('\n') && answer != expected
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Message of the payload of a panic, as caught by `catch_unwind`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "the solution panicked".to_string()
+    }
+}
+
+/// Runs every variant on `input`, catching their panics.
+pub fn run_input(variants: &[Variant], input: &str, answers: &[(u8, String)]) -> Vec<Run> {
+    variants
+        .iter()
+        .map(|variant| {
+            let (outcome, elapsed) =
+                match panic::catch_unwind(AssertUnwindSafe(|| variant.timed(input))) {
+                    Ok((Some(answer), elapsed)) => (Outcome::Solved(answer.to_string()), elapsed),
+                    Ok((None, elapsed)) => (Outcome::NotSolved, elapsed),
+                    Err(payload) => (
+                        Outcome::Panicked(panic_message(payload.as_ref())),
+                        Duration::ZERO,
+                    ),
+                };
+            let expected = answers
+                .iter()
+                .find(|(part, _)| *part == variant.part)
+                .map(|(_, answer)| answer.clone());
+            Run {
+                part: variant.part,
+                variant: variant.name,
+                outcome,
+                elapsed,
+                expected,
+            }
+        })
+        .collect()
+}
+
+/// Answers of the reference program `command` to each part of `input`, run once per
+/// part with `{part}` replaced by its number. Parts on which it fails are left out.
+pub fn reference_answers(
+    command: &str,
+    parts: impl IntoIterator<Item = u8>,
+    input: &str,
+) -> Vec<(u8, String)> {
+    parts
+        .into_iter()
+        .filter_map(|part| {
+            let command = command.replace("{part}", &part.to_string());
+            Some((part, minimize::run_command(&command, input)?))
+        })
+        .collect()
+}
+
+/// Indices of the `timings` of a variant over several inputs that are outliers, with
+/// their ratio to the median. At least three timings are needed to tell.
+pub fn outliers(timings: &[Duration]) -> Vec<(usize, f64)> {
+    if timings.len() < 3 {
+        return Vec::new();
+    }
+    let mut sorted = timings.to_vec();
+    sorted.sort();
+    let median = sorted[sorted.len() / 2].as_secs_f64().max(f64::EPSILON);
+    timings
+        .iter()
+        .enumerate()
+        .map(|(i, elapsed)| (i, *elapsed, elapsed.as_secs_f64() / median))
+        .filter(|&(_, elapsed, ratio)| elapsed >= OUTLIER_MIN && ratio > OUTLIER_FACTOR)
+        .map(|(i, _, ratio)| (i, ratio))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variants() -> Vec<Variant> {
+        vec![
+            Variant::new(1, "sum", |input: &str| {
+                Some(
+                    input
+                        .lines()
+                        .map(|line| line.parse::<u32>().unwrap())
+                        .sum::<u32>(),
+                )
+            }),
+            Variant::new(2, "count", |input: &str| Some(input.lines().count())),
+        ]
+    }
+
+    #[test]
+    fn test_run_input() {
+        let answers = vec![(1, "6".to_string()), (2, "2".to_string())];
+        let runs = run_input(&variants(), "1\n2\n3\n", &answers);
+        assert_eq!(runs[0].outcome, Outcome::Solved("6".to_string()));
+        assert!(!runs[0].failed());
+        assert_eq!(runs[1].outcome, Outcome::Solved("3".to_string()));
+        assert!(runs[1].failed());
+
+        let runs = run_input(&variants(), "1\nx\n", &[]);
+        assert!(
+            matches!(&runs[0].outcome, Outcome::Panicked(message) if message.contains("ParseIntError"))
+        );
+        assert!(runs[0].failed());
+        assert!(!runs[1].failed());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "spawns processes")]
+    fn test_reference_answers() {
+        let answers = reference_answers("test {part} = 1 && wc -l", [1, 2], "1\n2\n");
+        assert_eq!(answers, vec![(1, "2".to_string())]);
+
+        let runs = run_input(&variants(), "1\n2\n", &answers);
+        assert!(runs[0].failed());
+        assert_eq!(runs[1].expected, None);
+    }
+
+    #[test]
+    fn test_outliers() {
+        let ms = Duration::from_millis;
+        assert_eq!(outliers(&[ms(2), ms(3), ms(20)]), vec![(2, 20.0 / 3.0)]);
+        assert_eq!(outliers(&[ms(2), ms(20)]), vec![]);
+        assert_eq!(
+            outliers(&[
+                Duration::from_micros(1),
+                Duration::from_nanos(1),
+                Duration::from_nanos(1)
+            ]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_find_inputs() {
+        let dir = std::env::temp_dir().join(format!("adventofcode-batch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        for file in [
+            "05.txt",
+            "05.answers",
+            "alice/05_generated.txt",
+            "alice/01.txt",
+            "notes.txt",
+            "05x.txt",
+        ] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        fs::write(dir.join("05.answers"), "1 CMZ\n").unwrap();
+
+        let inputs = find_inputs(&dir).unwrap();
+        let found: Vec<_> = inputs
+            .iter()
+            .map(|input| {
+                (
+                    input.day,
+                    input.path.strip_prefix(&dir).unwrap().to_path_buf(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (1, PathBuf::from("alice/01.txt")),
+                (5, PathBuf::from("05.txt")),
+                (5, PathBuf::from("alice/05_generated.txt")),
+            ]
+        );
+        assert_eq!(inputs[1].answers(), vec![(1, "CMZ".to_string())]);
+        assert_eq!(inputs[2].answers(), vec![]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
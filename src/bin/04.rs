@@ -1,49 +1,8 @@
-use std::{ops::RangeInclusive, str::FromStr};
-
-use tuple::Map;
-
-fn parse_line(line: &str) -> (RangeInclusive<u32>, RangeInclusive<u32>) {
-    line.split_once(',').unwrap().map(|range| {
-        let (start, end) = range
-            .split_once('-')
-            .unwrap()
-            .map(|x| FromStr::from_str(x).unwrap());
-        start..=end
-    })
-}
-
-pub fn part_one(input: &str) -> Option<u32> {
-    let overlap_count = input
-        .lines()
-        .filter(|line| {
-            let (a, b) = parse_line(line);
-            (a.contains(b.start()) && a.contains(b.end()))
-                || (b.contains(a.start()) && b.contains(a.end()))
-        })
-        .count();
-
-    Some(overlap_count as u32)
-}
-
-pub fn part_two(input: &str) -> Option<u32> {
-    let overlap_count = input
-        .lines()
-        .filter(|line| {
-            let (a, b) = parse_line(line);
-            a.contains(b.start())
-                || a.contains(b.end())
-                || b.contains(a.start())
-                || b.contains(a.end())
-        })
-        .count();
-
-    Some(overlap_count as u32)
-}
+use adventofcode::day04::*;
 
 fn main() {
     let input = &adventofcode::read_file("inputs", 4);
-    adventofcode::solve!(1, part_one, input);
-    adventofcode::solve!(2, part_two, input);
+    adventofcode::variant::run(&variants(), input);
 }
 
 #[cfg(test)]
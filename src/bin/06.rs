@@ -1,24 +1,6 @@
 use std::collections::HashSet;
 
-use itertools::Itertools;
-
-pub fn part_one(input: &str) -> Option<usize> {
-    for (i, (a, b, c, d)) in input.chars().tuple_windows().enumerate() {
-        if a != b && a != c && a != d && b != c && b != d && c != d {
-            return Some(i + 4);
-        }
-    }
-    unreachable!()
-}
-
-pub fn part_two(input: &str) -> Option<usize> {
-    for (i, x) in input.as_bytes().windows(14).enumerate() {
-        if HashSet::<_>::from_iter(x).len() == 14 {
-            return Some(14 + i);
-        }
-    }
-    unreachable!()
-}
+use adventofcode::day06::*;
 
 fn main() {
     let input = &adventofcode::read_file("inputs", 6);
@@ -29,8 +11,7 @@ fn main() {
         let mut dummy = HashSet::with_capacity(1);
         dummy.insert(0u8);
     }
-    adventofcode::solve!(1, part_one, input);
-    adventofcode::solve!(2, part_two, input);
+    adventofcode::variant::run(&variants(), input);
 }
 
 #[cfg(test)]
@@ -1,118 +1,17 @@
-use itertools::Itertools;
-
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub enum Item {
-    Number(u32),
-    List(Box<[Item]>),
-}
-
-mod parser {
-    use adventofcode::helpers;
-    use nom::{
-        branch::alt, bytes::complete::tag, combinator::map, multi::separated_list0,
-        sequence::delimited, IResult,
-    };
-
-    use super::Item;
-
-    pub fn item(input: &str) -> IResult<&str, Item> {
-        alt((map(item_list, Item::List), map(item_number, Item::Number)))(input)
-    }
-
-    pub fn item_number(input: &str) -> IResult<&str, u32> {
-        helpers::parser::decimal_value(input)
-    }
-
-    pub fn item_list(input: &str) -> IResult<&str, Box<[Item]>> {
-        map(
-            delimited(tag("["), separated_list0(tag(","), item), tag("]")),
-            |items| items.into_boxed_slice(),
-        )(input)
-    }
-}
-
-fn parse_input(input: &str) -> impl Iterator<Item = Item> + '_ {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(parser::item)
-        .map(|x| x.unwrap().1)
-}
-
-impl PartialOrd for Item {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for Item {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        fn cmp_list(a: &[Item], b: &[Item]) -> std::cmp::Ordering {
-            if let Some(cmp) =
-                a.iter().zip(b).map(|(a, b)| Ord::cmp(a, b)).find(|cmp| {
-                    matches!(cmp, std::cmp::Ordering::Less | std::cmp::Ordering::Greater)
-                })
-            {
-                cmp
-            } else {
-                Ord::cmp(&a.len(), &b.len())
-            }
-        }
-
-        match (self, other) {
-            (Item::Number(a), Item::Number(b)) => a.cmp(b),
-            (a @ Item::Number(_), Item::List(b)) => cmp_list(std::slice::from_ref(a), b),
-            (Item::List(a), b @ Item::Number(_)) => cmp_list(a, std::slice::from_ref(b)),
-            (Item::List(a), Item::List(b)) => cmp_list(a, b),
-        }
-    }
-}
-
-pub fn part_one(input: &str) -> Option<usize> {
-    let count = parse_input(input)
-        .tuples()
-        .map(|(a, b)| Ord::cmp(&a, &b))
-        .enumerate()
-        .filter(|(_, cmp)| matches!(cmp, std::cmp::Ordering::Less))
-        .map(|(i, _)| i + 1)
-        .sum();
-
-    Some(count)
-}
-
-pub fn part_two(input: &str) -> Option<usize> {
-    let a = Item::List(vec![Item::List(vec![Item::Number(2)].into())].into());
-    let b = Item::List(vec![Item::List(vec![Item::Number(6)].into())].into());
-
-    let items = parse_input(input).chain([a.clone(), b.clone()]).sorted();
-
-    let mut pos_a = None;
-    let mut pos_b = None;
-
-    for (i, item) in items.enumerate() {
-        if pos_a.is_none() && a == item {
-            pos_a = Some(i + 1);
-        }
-        if pos_b.is_none() && b == item {
-            pos_b = Some(i + 1);
-        }
-        if pos_a.is_some() && pos_b.is_some() {
-            break;
-        }
-    }
-
-    Some(pos_a.unwrap() * pos_b.unwrap())
-}
+use adventofcode::day13::*;
 
 fn main() {
     let input = &adventofcode::read_file("inputs", 13);
-    adventofcode::solve!(1, part_one, input);
-    adventofcode::solve!(2, part_two, input);
+    adventofcode::variant::run(&variants(), input);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use itertools::Itertools;
+    use proptest::prelude::*;
+    use serde_json::Value;
+    use std::cmp::Ordering;
 
     #[test]
     fn test_part_one() {
@@ -125,4 +24,73 @@ mod tests {
         let input = adventofcode::read_file("examples", 13);
         assert_eq!(part_two(&input), Some(140));
     }
+
+    #[test]
+    fn test_deep_nesting() {
+        let depth = parser::MAX_DEPTH;
+        let line = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse_input(&line).all(|item| item.is_ok()));
+
+        let line = format!("[{line}]");
+        let error = parse_input(&line).next().unwrap().unwrap_err();
+        assert_eq!(error.code, nom::error::ErrorKind::TooLarge);
+        let line = "[".repeat(200_000);
+        let error = parse_input(&line).next().unwrap().unwrap_err();
+        assert_eq!(error.code, nom::error::ErrorKind::TooLarge);
+    }
+
+    fn packet() -> impl Strategy<Value = Item> {
+        let number = (0..=10u32).prop_map(Item::Number);
+        let item = number.prop_recursive(4, 32, 5, |inner| {
+            prop::collection::vec(inner, 0..5).prop_map(|items| Item::List(items.into()))
+        });
+        prop::collection::vec(item, 0..5).prop_map(|items| Item::List(items.into()))
+    }
+
+    fn to_string(item: &Item) -> String {
+        match item {
+            Item::Number(n) => n.to_string(),
+            Item::List(items) => format!("[{}]", items.iter().map(to_string).join(",")),
+        }
+    }
+
+    /// Compares packets following the rules of the puzzle to the letter.
+    fn reference_cmp(left: &Value, right: &Value) -> Ordering {
+        match (left, right) {
+            (Value::Number(a), Value::Number(b)) => a.as_u64().cmp(&b.as_u64()),
+            (Value::Array(a), Value::Array(b)) => {
+                for (a, b) in a.iter().zip(b) {
+                    match reference_cmp(a, b) {
+                        Ordering::Equal => continue,
+                        order => return order,
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+            (Value::Number(_), Value::Array(_)) => {
+                reference_cmp(&Value::Array(vec![left.clone()]), right)
+            }
+            (Value::Array(_), Value::Number(_)) => {
+                reference_cmp(left, &Value::Array(vec![right.clone()]))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_parser_roundtrip(packet in packet()) {
+            let input = to_string(&packet);
+            prop_assert_eq!(parser::item(&input), Ok(("", packet)));
+        }
+
+        #[test]
+        fn test_ord_matches_reference(left in packet(), right in packet()) {
+            let expected = reference_cmp(
+                &serde_json::from_str(&to_string(&left)).unwrap(),
+                &serde_json::from_str(&to_string(&right)).unwrap(),
+            );
+            prop_assert_eq!(left.cmp(&right), expected);
+        }
+    }
 }
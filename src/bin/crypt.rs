@@ -0,0 +1,81 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use adventofcode::crypt::{self, Key, KEY_FILE, KEY_VAR};
+use std::path::PathBuf;
+use std::process;
+
+enum Command {
+    Keygen,
+    Encrypt(PathBuf),
+    Decrypt(PathBuf),
+}
+
+fn parse_args() -> Result<Command, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let command: String = args.free_from_str()?;
+    let dir = args
+        .opt_free_from_str()?
+        .unwrap_or_else(|| PathBuf::from("src/inputs"));
+    match command.as_str() {
+        "keygen" => Ok(Command::Keygen),
+        "encrypt" => Ok(Command::Encrypt(dir)),
+        "decrypt" => Ok(Command::Decrypt(dir)),
+        _ => Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unknown command \"{command}\""),
+        }),
+    }
+}
+
+fn main() {
+    let command = match parse_args() {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo crypt encrypt`");
+            process::exit(1);
+        }
+    };
+
+    let (dir, encrypt) = match command {
+        Command::Keygen => {
+            println!("{}", Key::generate());
+            eprintln!("🎄 Store this key in {KEY_VAR} or in ~/{KEY_FILE}.");
+            return;
+        }
+        Command::Encrypt(dir) => (dir, true),
+        Command::Decrypt(dir) => (dir, false),
+    };
+
+    let key = match Key::load() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("Failed to load the key: {e}");
+            process::exit(1);
+        }
+    };
+
+    let result = if encrypt {
+        crypt::encrypt_dir(&key, &dir)
+    } else {
+        crypt::decrypt_dir(&key, &dir)
+    };
+
+    match result {
+        Ok(paths) => {
+            for path in &paths {
+                println!("Wrote \"{}\"", path.display());
+            }
+            let action = if encrypt { "Encrypted" } else { "Decrypted" };
+            println!(
+                "🎄 {action} {} file(s) in \"{}\".",
+                paths.len(),
+                dir.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to process \"{}\": {e}", dir.display());
+            process::exit(1);
+        }
+    }
+}
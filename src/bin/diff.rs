@@ -0,0 +1,132 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Compares the answers of the variants of a day with a reference program, configured in
+//! `aoc.toml` or given with `--command`, on the input of the day, on the inputs of
+//! `--inputs-dir DIR` and on `--generate N` generated inputs.
+
+use std::path::PathBuf;
+use std::{fs, panic, process};
+
+use adventofcode::batch::{self, Outcome};
+use adventofcode::config::config;
+use adventofcode::generate::generate;
+use adventofcode::{ANSI_BOLD, ANSI_RESET};
+use itertools::Itertools;
+
+struct Args {
+    day: u8,
+    command: Option<String>,
+    inputs_dir: Option<PathBuf>,
+    generate: usize,
+    size: usize,
+    seed: u64,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        command: args.opt_value_from_str("--command")?,
+        inputs_dir: args.opt_value_from_str("--inputs-dir")?,
+        generate: args.opt_value_from_str("--generate")?.unwrap_or(0),
+        size: args.opt_value_from_str("--size")?.unwrap_or(100),
+        seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+        day: args.free_from_str()?,
+    })
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => fail(format!(
+            "Failed to process arguments: {e}. example: `cargo diff 5 --generate 100`"
+        )),
+    };
+    let day = args.day;
+    let Some(variants) = adventofcode::variants(day) else {
+        fail(format!("Day {day} is not solved."));
+    };
+    let Some(command) = args.command.as_deref().or_else(|| config().reference(day)) else {
+        fail(format!(
+            "There is no reference for day {day} in aoc.toml, see --command."
+        ));
+    };
+
+    // the inputs, and how to get them back.
+    let mut inputs: Vec<(String, String)> = Vec::new();
+    if let Some(input) = adventofcode::try_read_file("inputs", day) {
+        inputs.push(("the input".to_string(), input));
+    }
+    if let Some(dir) = &args.inputs_dir {
+        let found = batch::find_inputs(dir)
+            .unwrap_or_else(|e| fail(format!("Failed to read \"{}\": {e}", dir.display())));
+        for input in found.into_iter().filter(|input| input.day == day) {
+            match fs::read_to_string(&input.path) {
+                Ok(content) => inputs.push((format!("\"{}\"", input.path.display()), content)),
+                Err(e) => eprintln!("Failed to read \"{}\": {e}", input.path.display()),
+            }
+        }
+    }
+    for seed in (args.seed..).take(args.generate) {
+        let Some(input) = generate(day, seed, args.size) else {
+            fail(format!("There is no generator for day {day}."));
+        };
+        let how = format!("`cargo generate {day} --seed {seed} --size {}`", args.size);
+        inputs.push((how, input));
+    }
+    if inputs.is_empty() {
+        fail(format!(
+            "There is no input for day {day}, see --inputs-dir and --generate."
+        ));
+    }
+
+    // panics are disagreements, unless the reference fails too.
+    panic::set_hook(Box::new(|_| {}));
+
+    let parts = variants
+        .iter()
+        .map(|variant| variant.part)
+        .unique()
+        .collect_vec();
+    let mut disagreements = 0;
+    for (name, input) in &inputs {
+        let answers = batch::reference_answers(command, parts.iter().copied(), input);
+        for &part in &parts {
+            if !answers.iter().any(|(other, _)| *other == part) {
+                eprintln!("The reference failed on part {part} of {name}.");
+            }
+        }
+        for run in batch::run_input(&variants, input, &answers) {
+            let Some(expected) = run.expected.as_deref().filter(|_| run.failed()) else {
+                continue;
+            };
+            disagreements += 1;
+            let ours = match &run.outcome {
+                Outcome::Solved(answer) => answer.clone(),
+                Outcome::NotSolved => "not solved".to_string(),
+                Outcome::Panicked(message) => format!("panicked: {message}"),
+            };
+            println!(
+                "❌ {} answered {ours} instead of {expected} on {name}.",
+                run.variant
+            );
+        }
+    }
+
+    println!(
+        "{ANSI_BOLD}{} inputs:{ANSI_RESET} {disagreements} disagreements with `{command}`",
+        inputs.len()
+    );
+    if disagreements > 0 {
+        eprintln!(
+            "Shrink a failing input with `cargo minimize {day} PART --input PATH --command CMD`."
+        );
+        process::exit(1);
+    }
+}
@@ -2,21 +2,38 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use adventofcode::aoc::{check_input, format_duration, unlock_time};
+use adventofcode::config::config;
 use std::io::Write;
 use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
 use std::{env::temp_dir, io, process::Command};
 use std::{fs, process};
 
+const DEFAULT_RETRIES: u32 = 3;
+/// Longest wait between two attempts, in seconds.
+const MAX_BACKOFF: u64 = 60;
+
 struct Args {
     day: u8,
     year: Option<i16>,
+    wait: bool,
+    retries: u32,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let wait = args.contains(["-w", "--wait"]);
+    let retries = args
+        .opt_value_from_str(["-r", "--retries"])?
+        .unwrap_or(DEFAULT_RETRIES);
     Ok(Args {
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        year,
+        wait,
+        retries,
     })
 }
 
@@ -32,12 +49,71 @@ fn exit_with_status(status: i32, path: &PathBuf) -> ! {
     process::exit(status);
 }
 
+fn wait_for_unlock(year: i32, day: u8) {
+    let Some(unlock) = unlock_time(year, day) else {
+        return;
+    };
+    if let Ok(remaining) = unlock.duration_since(SystemTime::now()) {
+        println!(
+            "Day {day} of {year} unlocks in {}, waiting...",
+            format_duration(remaining)
+        );
+        sleep(remaining);
+    }
+}
+
+/// Wait before retrying after the failed `attempt`, doubling up to `MAX_BACKOFF`.
+fn backoff(attempt: u32) -> Duration {
+    Duration::from_secs(
+        1u64.checked_shl(attempt)
+            .unwrap_or(u64::MAX)
+            .min(MAX_BACKOFF),
+    )
+}
+
+struct DownloadError {
+    message: String,
+    retryable: bool,
+}
+
+fn download(cmd_args: &[String], tmp_file_path: &PathBuf) -> Result<(), DownloadError> {
+    // aoc-cli expects the output file not to be present.
+    remove_file(tmp_file_path);
+
+    let cmd_output = Command::new("aoc")
+        .args(cmd_args)
+        .output()
+        .map_err(|e| DownloadError {
+            message: format!("failed to spawn aoc-cli: {e}"),
+            retryable: false,
+        })?;
+    io::stdout()
+        .write_all(&cmd_output.stdout)
+        .expect("could not write cmd stdout to pipe.");
+    io::stderr()
+        .write_all(&cmd_output.stderr)
+        .expect("could not write cmd stderr to pipe.");
+    if !cmd_output.status.success() {
+        return Err(DownloadError {
+            message: format!("aoc-cli exited with {}", cmd_output.status),
+            retryable: true,
+        });
+    }
+
+    let content = fs::read_to_string(tmp_file_path).map_err(|e| DownloadError {
+        message: format!("could not read downloaded input: {e}"),
+        retryable: true,
+    })?;
+    check_input(&content).map_err(|e| DownloadError {
+        message: format!("refusing to save the input: {e}"),
+        retryable: e.is_retryable(),
+    })
+}
+
 fn main() {
     // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
     let mut tmp_file_path = temp_dir();
     tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
 
     let args = match parse_args() {
         Ok(args) => args,
@@ -48,7 +124,11 @@ fn main() {
     };
 
     let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{day_padded}.txt");
+    let input_path = config()
+        .inputs_dir
+        .join(format!("{day_padded}.txt"))
+        .to_string_lossy()
+        .into_owned();
 
     // check if aoc binary exists and is callable.
     if Command::new("aoc").arg("-V").output().is_err() {
@@ -58,11 +138,17 @@ fn main() {
 
     let mut cmd_args = vec![];
 
-    if let Some(year) = args.year {
+    let year = args.year.map(i32::from).or(config().year);
+    if let Some(year) = year {
         cmd_args.push("--year".into());
         cmd_args.push(year.to_string());
     }
 
+    if let Some(session_file) = config().session_file() {
+        cmd_args.push("--session-file".into());
+        cmd_args.push(session_file.to_string_lossy().to_string());
+    }
+
     cmd_args.append(&mut vec![
         "--input-file".into(),
         tmp_file_path.to_string_lossy().to_string(),
@@ -71,30 +157,32 @@ fn main() {
         "download".into(),
     ]);
 
+    if args.wait {
+        wait_for_unlock(year.unwrap_or_else(|| config().year()), args.day);
+    }
+
     println!("Downloading input with >aoc {}", cmd_args.join(" "));
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+    let mut attempt = 0;
+    while let Err(e) = download(&cmd_args, &tmp_file_path) {
+        eprintln!("{}", e.message);
+        if !e.retryable || attempt == args.retries {
             exit_with_status(1, &tmp_file_path);
         }
+        let backoff = backoff(attempt);
+        attempt += 1;
+        eprintln!(
+            "Retrying in {}s ({attempt}/{})...",
+            backoff.as_secs(),
+            args.retries
+        );
+        sleep(backoff);
     }
 
     match fs::copy(&tmp_file_path, &input_path) {
         Ok(_) => {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+            println!("🎄 Successfully wrote input to \"{}\".", input_path);
             exit_with_status(0, &tmp_file_path);
         }
         Err(e) => {
@@ -103,3 +191,17 @@ fn main() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff(0), Duration::from_secs(1));
+        assert_eq!(backoff(3), Duration::from_secs(8));
+        assert_eq!(backoff(6), Duration::from_secs(MAX_BACKOFF));
+        assert_eq!(backoff(64), Duration::from_secs(MAX_BACKOFF));
+        assert_eq!(backoff(u32::MAX), Duration::from_secs(MAX_BACKOFF));
+    }
+}
@@ -0,0 +1,54 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use adventofcode::generate::generate;
+use std::path::PathBuf;
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    size: usize,
+    seed: Option<u64>,
+    output: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        size: args.opt_value_from_str("--size")?.unwrap_or(100),
+        seed: args.opt_value_from_str("--seed")?,
+        output: args.opt_value_from_str("--output")?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo generate 7 --size 500`");
+            process::exit(1);
+        }
+    };
+
+    let seed = args.seed.unwrap_or_else(rand::random);
+    let Some(input) = generate(args.day, seed, args.size) else {
+        eprintln!("There is no generator for day {}.", args.day);
+        process::exit(1);
+    };
+
+    match &args.output {
+        Some(path) => {
+            if let Err(e) = fs::write(path, &input) {
+                eprintln!("Failed to write \"{}\": {e}", path.display());
+                process::exit(1);
+            }
+            eprintln!("🎄 Wrote \"{}\" with seed {seed}.", path.display());
+        }
+        None => {
+            print!("{input}");
+            eprintln!("🎄 Generated with seed {seed}.");
+        }
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use adventofcode::inspect::inspect;
+use std::process;
+
+struct Args {
+    day: u8,
+    example: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        example: args.contains("--example"),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo inspect 18`");
+            process::exit(1);
+        }
+    };
+
+    let folder = if args.example { "examples" } else { "inputs" };
+    let Some(input) = adventofcode::try_read_file(folder, args.day) else {
+        eprintln!("There is no file for day {} in {folder}.", args.day);
+        process::exit(1);
+    };
+
+    println!("{}", inspect(&input));
+}
@@ -0,0 +1,75 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use adventofcode::aoc;
+use adventofcode::config::config;
+use adventofcode::leaderboard::{render_day, render_ranking, Leaderboard};
+use std::path::PathBuf;
+use std::{fs, process};
+
+struct Args {
+    file: Option<PathBuf>,
+    id: Option<u64>,
+    year: Option<i32>,
+    day: Option<u8>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        file: args.opt_value_from_str(["-f", "--file"])?,
+        id: args.opt_value_from_str(["-i", "--id"])?,
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.opt_value_from_str(["-d", "--day"])?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let json = match (&args.file, args.id) {
+        (Some(path), _) => fs::read_to_string(path)
+            .map_err(|e| format!("could not read \"{}\": {e}", path.display())),
+        (None, Some(id)) => {
+            let year = args.year.unwrap_or_else(|| config().year());
+            aoc::fetch_leaderboard(year, id)
+        }
+        (None, None) => {
+            eprintln!("Need to specify a leaderboard. example: `cargo leaderboard --id 123456` or `cargo leaderboard --file leaderboard.json`");
+            process::exit(1);
+        }
+    };
+
+    let leaderboard = match json
+        .and_then(|json| Leaderboard::parse(&json).map_err(|e| format!("invalid leaderboard: {e}")))
+    {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("Failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("{}", render_ranking(&leaderboard));
+
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => leaderboard.days(),
+    };
+    for day in days {
+        match render_day(&leaderboard, day) {
+            Ok(output) => println!("{output}"),
+            Err(e) => {
+                eprintln!("Failed to render day {day}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
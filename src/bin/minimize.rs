@@ -0,0 +1,166 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Shrinks an input on which a part disagrees with a reference, another variant with
+//! `--reference NAME` or a program with `--command CMD`, and writes it with the answer
+//! of the reference to the examples as `NN_NAME.txt` and `NN_NAME.answers`.
+//!
+//! Parameters are given with `--param NAME=VALUE`, and the input is then only printed.
+//! A recorded answer (`--answer`, or else the accepted one in `submissions/`) only
+//! holds for the whole input, so it tells which side is wrong before shrinking.
+
+use std::path::{Path, PathBuf};
+use std::{fs, panic, process};
+
+use adventofcode::config::config;
+use adventofcode::minimize::{minimize, solve, Granularity, Reference};
+use adventofcode::variant::Params;
+use adventofcode::{puzzle, submissions};
+
+struct Args {
+    day: u8,
+    part: u8,
+    variant: Option<String>,
+    reference: Option<String>,
+    command: Option<String>,
+    answer: Option<String>,
+    input: Option<PathBuf>,
+    params: Vec<String>,
+    sections: bool,
+    name: String,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        variant: args.opt_value_from_str("--variant")?,
+        reference: args.opt_value_from_str("--reference")?,
+        command: args.opt_value_from_str("--command")?,
+        answer: args.opt_value_from_str("--answer")?,
+        input: args.opt_value_from_str("--input")?,
+        params: args.values_from_str("--param")?,
+        sections: args.contains("--sections"),
+        name: args
+            .opt_value_from_str("--name")?
+            .unwrap_or_else(|| "min".to_string()),
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+    })
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => fail(format!(
+            "Failed to process arguments: {e}. example: `cargo minimize 15 1 --reference part_one_2`"
+        )),
+    };
+    let (day, part) = (args.day, args.part);
+
+    let variants =
+        adventofcode::variants(day).unwrap_or_else(|| fail(format!("Day {day} is not solved.")));
+    let find = |name: Option<&str>| {
+        variants
+            .iter()
+            .filter(|variant| variant.part == part)
+            .find(|variant| name.is_none_or(|name| variant.name == name))
+            .unwrap_or_else(|| fail(format!("Day {day} has no such variant of part {part}.")))
+    };
+    let variant = find(args.variant.as_deref());
+    let reference = match (&args.reference, &args.command) {
+        (Some(name), None) => Reference::Variant(find(Some(name))),
+        (None, Some(command)) => Reference::Command(command.clone()),
+        _ => fail("Expected either --reference VARIANT or --command CMD.".to_string()),
+    };
+
+    let input = match &args.input {
+        Some(path) => fs::read_to_string(path)
+            .unwrap_or_else(|e| fail(format!("Failed to read \"{}\": {e}", path.display()))),
+        None => adventofcode::try_read_file("inputs", day)
+            .unwrap_or_else(|| fail(format!("There is no input for day {day}."))),
+    };
+    let params: Params = args
+        .params
+        .iter()
+        .map(|param| {
+            param
+                .split_once('=')
+                .unwrap_or_else(|| fail(format!("Expected --param NAME=VALUE, got \"{param}\".")))
+        })
+        .collect();
+
+    // the answers of the tested variant are compared, not printed, when it panics.
+    panic::set_hook(Box::new(|_| {}));
+
+    let recorded = args.answer.clone().or_else(|| {
+        let submissions = submissions::load(Path::new("submissions"), day);
+        submissions::correct_answer(&submissions, part).map(str::to_string)
+    });
+    if let Some(recorded) = recorded {
+        let answer = solve(variant, &input, &params);
+        let expected = reference.answer(&input, &params);
+        eprintln!(
+            "Recorded answer {recorded}: {} {}, the reference {}.",
+            variant.name,
+            verdict(answer.as_deref(), &recorded),
+            verdict(expected.as_deref(), &recorded)
+        );
+    }
+
+    let granularity = if args.sections {
+        Granularity::Sections
+    } else {
+        Granularity::Lines
+    };
+    let Some((minimized, expected)) = minimize(variant, &reference, &input, &params, granularity)
+    else {
+        fail(format!(
+            "{} agrees with the reference on this input.",
+            variant.name
+        ));
+    };
+
+    print!("{minimized}");
+    let summary = format!(
+        "Shrunk to {} lines, expecting {expected} (got {})",
+        minimized.lines().count(),
+        solve(variant, &minimized, &params).unwrap_or_default(),
+    );
+    // the example tests run the parts without parameters.
+    if !args.params.is_empty() {
+        eprintln!("🎄 {summary}, not written as the answers files have no parameters.");
+        return;
+    }
+
+    let examples = config().dir("examples");
+    let stem = format!("{day:02}_{}", args.name);
+    let input_path = examples.join(format!("{stem}.txt"));
+    let answers_path = examples.join(format!("{stem}.answers"));
+    if input_path.exists() || answers_path.exists() {
+        fail(format!(
+            "\"{}\" already exists, see --name.",
+            input_path.display()
+        ));
+    }
+    let answers = puzzle::format_answers([(part, expected.as_str())]);
+    if let Err(e) =
+        fs::write(&input_path, &minimized).and_then(|_| fs::write(&answers_path, answers))
+    {
+        fail(format!("Failed to write \"{}\": {e}", input_path.display()));
+    }
+    eprintln!("🎄 {summary}, written to \"{}\".", input_path.display());
+}
+
+fn verdict(answer: Option<&str>, recorded: &str) -> &'static str {
+    match answer {
+        Some(answer) if answer == recorded => "agrees",
+        Some(_) => "disagrees",
+        None => "has no answer",
+    }
+}
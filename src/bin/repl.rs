@@ -0,0 +1,344 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Interactive shell to load the input of a day, look at its parsed model and run its
+//! parts with parameters, see `help` for the commands.
+
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::{fs, process};
+
+use adventofcode::batch::panic_message;
+use adventofcode::variant::{Params, Variant};
+use adventofcode::{day05, day07, day11, day16, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use itertools::Itertools;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+
+const HELP: &str = "\
+day N               load day N with its input, or its example if there is no input
+input | example     load the input or the example of the day
+load PATH           load the input from PATH
+model               print the parsed model of the input (days 5, 7, 11 and 16)
+run PART [VARIANT]  run a part, with its first variant by default
+set NAME=VALUE      set a parameter of the parts, such as target_line for day 15
+unset NAME          remove a parameter
+params              print the parameters
+status              print the loaded day, input and parameters
+help                print this help
+quit                exit";
+
+struct Repl {
+    day: Option<u8>,
+    variants: Vec<Variant>,
+    source: String,
+    input: String,
+    params: Vec<(String, String)>,
+}
+
+impl Repl {
+    fn new() -> Repl {
+        Repl {
+            day: None,
+            variants: Vec::new(),
+            source: String::new(),
+            input: String::new(),
+            params: Vec::new(),
+        }
+    }
+
+    fn day(&self) -> Result<u8, String> {
+        self.day
+            .ok_or_else(|| "no day loaded, try `day 5`.".to_string())
+    }
+
+    fn load_day(&mut self, day: &str) -> Result<String, String> {
+        let day: u8 = day.parse().map_err(|_| format!("invalid day \"{day}\"."))?;
+        self.variants = adventofcode::variants(day).ok_or(format!("day {day} is not solved."))?;
+        self.day = Some(day);
+        self.input = String::new();
+        self.source = String::new();
+        self.load_folder("inputs")
+            .or_else(|_| self.load_folder("examples"))
+    }
+
+    fn load_folder(&mut self, folder: &str) -> Result<String, String> {
+        let day = self.day()?;
+        let input = adventofcode::try_read_file(folder, day)
+            .ok_or(format!("there is no file for day {day} in {folder}."))?;
+        self.load(folder.trim_end_matches('s').to_string(), input)
+    }
+
+    fn load_path(&mut self, path: &str) -> Result<String, String> {
+        self.day()?;
+        let input =
+            fs::read_to_string(path).map_err(|e| format!("failed to read \"{path}\": {e}"))?;
+        self.load(format!("\"{path}\""), input)
+    }
+
+    fn load(&mut self, source: String, input: String) -> Result<String, String> {
+        self.source = source;
+        self.input = input;
+        Ok(self.status())
+    }
+
+    fn status(&self) -> String {
+        let Some(day) = self.day else {
+            return "no day loaded.".to_string();
+        };
+        let mut status = format!(
+            "day {day}, {} ({} lines)",
+            self.source,
+            self.input.lines().count()
+        );
+        if !self.params.is_empty() {
+            write!(status, ", {}", self.format_params()).unwrap();
+        }
+        status
+    }
+
+    fn format_params(&self) -> String {
+        self.params
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .join(" ")
+    }
+
+    fn set(&mut self, assignment: &str) -> Result<String, String> {
+        let (name, value) = assignment
+            .split_once('=')
+            .ok_or(format!("expected NAME=VALUE, got \"{assignment}\"."))?;
+        self.params.retain(|(other, _)| other != name);
+        self.params.push((name.to_string(), value.to_string()));
+        Ok(self.format_params())
+    }
+
+    fn run(&self, part: &str, name: Option<&str>) -> Result<String, String> {
+        let day = self.day()?;
+        let part: u8 = part
+            .parse()
+            .map_err(|_| format!("invalid part \"{part}\"."))?;
+        let variant = self
+            .variants
+            .iter()
+            .filter(|variant| variant.part == part)
+            .find(|variant| name.is_none_or(|name| variant.name == name))
+            .ok_or(format!("day {day} has no such part or variant."))?;
+
+        let params: Params = self.params.iter().cloned().collect();
+        let (answer, elapsed) = catch_panic(|| variant.timed_with(&self.input, &params))?;
+        Ok(match answer {
+            Some(answer) => format!(
+                "{answer} {ANSI_ITALIC}({}, elapsed: {elapsed:.2?}){ANSI_RESET}",
+                variant.name
+            ),
+            None => "not solved.".to_string(),
+        })
+    }
+
+    fn model(&self) -> Result<String, String> {
+        let day = self.day()?;
+        let input = self.input.as_str();
+        catch_panic(|| model(day, input))?
+    }
+
+    /// Runs a command, returning what to print.
+    fn eval(&mut self, line: &str) -> Result<String, String> {
+        let words = line.split_whitespace().collect_vec();
+        match words.as_slice() {
+            [] => Ok(String::new()),
+            ["day", day] => self.load_day(day),
+            ["input"] => self.load_folder("inputs"),
+            ["example"] => self.load_folder("examples"),
+            ["load", path] => self.load_path(path),
+            ["model"] => self.model(),
+            ["run", part] => self.run(part, None),
+            ["run", part, name] => self.run(part, Some(name)),
+            ["set", assignment] => self.set(assignment),
+            ["unset", name] => {
+                self.params.retain(|(other, _)| other != name);
+                Ok(self.format_params())
+            }
+            ["params"] => Ok(self.format_params()),
+            ["status"] => Ok(self.status()),
+            ["help"] => Ok(HELP.to_string()),
+            _ => Err(format!("unknown command \"{line}\", see `help`.")),
+        }
+    }
+}
+
+/// Solutions panic on invalid inputs and parameters, which must not end the session.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(|payload| format!("the solution panicked: {}", panic_message(payload.as_ref())))
+}
+
+/// Parsed model of the input of `day`, for the days that have an interesting one.
+fn model(day: u8, input: &str) -> Result<String, String> {
+    let lines = match day {
+        5 => {
+            let (stacks, _) = day05::parse_input(input);
+            stacks
+                .iter()
+                .enumerate()
+                .map(|(i, stack)| format!("{} {}", i + 1, String::from_utf8_lossy(&stack.crates)))
+                .collect_vec()
+        }
+        7 => day07::parse_input(input)
+            .into_iter()
+            .sorted()
+            .map(|(path, size)| format!("{path} {size}"))
+            .collect_vec(),
+        11 => day11::parse_monkeys(input)
+            .iter()
+            .enumerate()
+            .map(|(i, monkey)| {
+                let operation = match monkey.operation {
+                    day11::Operation::Add(n) => format!("old + {n}"),
+                    day11::Operation::Mul(n) => format!("old * {n}"),
+                    day11::Operation::Square => "old * old".to_string(),
+                };
+                format!(
+                    "monkey {i}: items {:?}, new = {operation}, divisible by {} ? {} : {}",
+                    monkey.items.iter().map(|item| item.0).collect_vec(),
+                    monkey.test,
+                    monkey.if_true,
+                    monkey.if_false
+                )
+            })
+            .collect_vec(),
+        16 => day16::parse_input(input)
+            .map_err(|e| format!("invalid input: {e}"))?
+            .into_iter()
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(name, valve)| {
+                format!(
+                    "{name} rate {} -> {}",
+                    valve.flow_rate,
+                    valve.neighbor_valves.join(", ")
+                )
+            })
+            .collect_vec(),
+        _ => return Err(format!("day {day} has no model to show.")),
+    };
+    Ok(lines.join("\n"))
+}
+
+fn main() {
+    let mut editor = match DefaultEditor::new() {
+        Ok(editor) => editor,
+        Err(e) => {
+            eprintln!("Failed to start the shell: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut repl = Repl::new();
+    if let Some(day) = std::env::args().nth(1) {
+        match repl.load_day(&day) {
+            Ok(status) => println!("{status}"),
+            Err(e) => eprintln!("{e}"),
+        }
+    }
+    println!("🎄 {ANSI_BOLD}Type `help` for the commands.{ANSI_RESET}");
+
+    loop {
+        let prompt = match repl.day {
+            Some(day) => format!("day {day:02}> "),
+            None => "> ".to_string(),
+        };
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(e) => {
+                eprintln!("Failed to read the command: {e}");
+                process::exit(1);
+            }
+        };
+        let line = line.trim();
+        if line == "quit" || line == "exit" {
+            break;
+        }
+        let _ = editor.add_history_entry(line);
+        match repl.eval(line) {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => println!("{output}"),
+            Err(e) => eprintln!("{e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repl(day: u8) -> Repl {
+        let mut repl = Repl::new();
+        repl.load_day(&day.to_string()).unwrap();
+        repl.eval("example").unwrap();
+        repl
+    }
+
+    #[test]
+    fn test_run() {
+        let mut repl = repl(15);
+        repl.eval("set target_line=x").unwrap();
+        assert_eq!(
+            repl.eval("run 1"),
+            Err("the solution panicked: invalid value of parameter target_line: \"x\"".to_string())
+        );
+        repl.eval("set target_line=10").unwrap();
+        assert!(repl.eval("run 1").unwrap().starts_with("26 "));
+        repl.eval("set max_coordinate=20").unwrap();
+        assert!(repl.eval("run 2").unwrap().starts_with("56000011 "));
+        assert_eq!(
+            repl.eval("params").unwrap(),
+            "target_line=10 max_coordinate=20"
+        );
+        assert!(repl.eval("run 3").is_err());
+    }
+
+    #[test]
+    fn test_variants() {
+        let mut repl = repl(2);
+        assert!(repl
+            .eval("run 1 part_one_2")
+            .unwrap()
+            .contains("part_one_2"));
+        assert!(repl.eval("run 1 part_one_x").is_err());
+    }
+
+    #[test]
+    fn test_model() {
+        assert_eq!(repl(5).eval("model").unwrap(), "1 ZN\n2 MCD\n3 P");
+        assert_eq!(
+            repl(7).eval("model").unwrap(),
+            "/ 48381165\n//a 94853\n//a/e 584\n//d 24933642"
+        );
+        assert!(repl(11)
+            .eval("model")
+            .unwrap()
+            .starts_with("monkey 0: items [79, 98], new = old * 19, divisible by 23 ? 2 : 3\n"));
+        assert!(repl(16)
+            .eval("model")
+            .unwrap()
+            .starts_with("AA rate 0 -> DD, II, BB\nBB rate 13 -> CC, AA\n"));
+        assert!(repl(1).eval("model").is_err());
+    }
+
+    #[test]
+    fn test_commands() {
+        let mut repl = Repl::new();
+        assert!(repl.eval("run 1").is_err());
+        assert!(repl.eval("day 30").is_err());
+        assert!(repl.eval("frobnicate").is_err());
+        assert_eq!(repl.eval("").unwrap(), "");
+        assert!(repl.eval("load src/examples/01.txt").is_err());
+        repl.eval("day 1").unwrap();
+        assert_eq!(
+            repl.eval("load src/examples/01.txt").unwrap(),
+            "day 1, \"src/examples/01.txt\" (14 lines)"
+        );
+    }
+}
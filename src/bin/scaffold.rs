@@ -2,13 +2,19 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use adventofcode::config::config;
+use adventofcode::submissions::{self, Submission, Verdict};
+use adventofcode::{aoc, puzzle};
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
+const MODULE_TEMPLATE: &str = r###"use crate::variant::Variant;
+
+pub fn part_one(input: &str) -> Option<u32> {
     None
 }
 
@@ -16,10 +22,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     None
 }
 
+pub fn variants() -> Vec<Variant> {
+    vec![
+        Variant::new(1, "part_one", part_one),
+        Variant::new(2, "part_two", part_two),
+    ]
+}
+"###;
+
+const BIN_TEMPLATE: &str = r###"use adventofcode::MODULE::*;
+
 fn main() {
     let input = &adventofcode::read_file("inputs", DAY);
-    adventofcode::solve!(1, part_one, input);
-    adventofcode::solve!(2, part_two, input);
+    adventofcode::variant::run(&variants(), input);
 }
 
 #[cfg(test)]
@@ -29,20 +44,35 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = adventofcode::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        assert_eq!(part_one(&input), PART_ONE_ANSWER);
     }
 
     #[test]
     fn test_part_two() {
         let input = adventofcode::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        assert_eq!(part_two(&input), PART_TWO_ANSWER);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+struct Args {
+    day: u8,
+    year: Option<i32>,
+    puzzle_file: Option<PathBuf>,
+    puzzle_only: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let puzzle_file = args.opt_value_from_str(["-f", "--puzzle-file"])?;
+    let puzzle_only = args.contains(["-p", "--puzzle-only"]);
+    Ok(Args {
+        day: args.free_from_str()?,
+        year,
+        puzzle_file,
+        puzzle_only,
+    })
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
@@ -50,65 +80,281 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Inserts `line` into the list of lines that have a key, keeping the list sorted.
+fn insert_sorted<K: Ord>(lines: &mut Vec<String>, line: String, key: impl Fn(&str) -> Option<K>) {
+    let new_key = key(&line);
+    let keyed = || lines.iter().enumerate().filter(|(_, l)| key(l).is_some());
+    let index = keyed()
+        .find(|(_, l)| key(l) > new_key)
+        .or_else(|| keyed().last())
+        .map(|(index, l)| if key(l) > new_key { index } else { index + 1 })
+        .unwrap_or(lines.len());
+    lines.insert(index, line);
+}
+
+/// Declares the module of `day` in the day modules at `path` and registers its variants.
+fn register_module(path: &str, day: u8) -> Result<(), std::io::Error> {
+    let content = fs::read_to_string(path)?;
+    let module = format!("day{day:02}");
+    let declaration = format!("pub mod {module};");
+    if content.lines().any(|line| line == declaration) {
+        return Ok(());
+    }
+
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    insert_sorted(&mut lines, declaration, |line| {
+        line.strip_prefix("pub mod ").map(String::from)
+    });
+    insert_sorted(
+        &mut lines,
+        format!("        {day} => {module}::variants(),"),
+        |line| line.trim().split_once(" => day")?.0.parse::<u8>().ok(),
+    );
+    fs::write(path, lines.join("\n") + "\n")
+}
+
+fn is_empty_file(path: &str) -> bool {
+    fs::metadata(path).map_or(true, |metadata| metadata.len() == 0)
+}
+
+fn read_puzzle(args: &Args) -> Result<String, String> {
+    match &args.puzzle_file {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| format!("could not read \"{}\": {e}", path.display())),
+        None => {
+            let year = args.year.unwrap_or_else(|| config().year());
+            aoc::fetch_puzzle(year, args.day)
+        }
+    }
+}
+
+/// Writes the description and the example answers of the puzzle.
+fn write_puzzle(html: &str, puzzle_path: &str, answers_path: &str) {
+    if let Err(e) = fs::create_dir_all("puzzles")
+        .and_then(|_| fs::write(puzzle_path, puzzle::to_markdown(html)))
+    {
+        eprintln!("Failed to write puzzle description: {e}");
+        process::exit(1);
+    }
+    println!("Wrote puzzle description to \"{puzzle_path}\"");
+
+    let answers = puzzle::example_answers(html);
+    let answers = answers
+        .iter()
+        .zip(1..)
+        .filter_map(|(answer, part)| Some((part, answer.as_deref()?)));
+    for (part, answer) in answers.clone() {
+        println!("Part {part} example answer: {answer}");
+    }
+    if let Err(e) = fs::write(answers_path, puzzle::format_answers(answers)) {
+        eprintln!("Failed to write example answers: {e}");
+        process::exit(1);
+    }
+}
+
+/// Records the answers shown on the puzzle page as correct submissions.
+fn record_puzzle_answers(html: &str, day: u8) {
+    let submissions_dir = Path::new("submissions");
+    let previous = submissions::load(submissions_dir, day);
+    for (answer, part) in puzzle::puzzle_answers(html).into_iter().zip(1..) {
+        if submissions::correct_answer(&previous, part).is_some() {
+            continue;
+        }
+        let submission = Submission {
+            part,
+            verdict: Verdict::Correct,
+            answer,
+        };
+        match submissions::record(submissions_dir, day, &submission) {
+            Ok(_) => {
+                println!("Recorded answer {} of part {part}", submission.answer);
+            }
+            Err(e) => {
+                eprintln!("Failed to record puzzle answer: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Writes the example of the puzzle unless the example file is already filled.
+fn write_example(html: Option<&str>, example_path: &str) {
+    if !is_empty_file(example_path) {
+        return;
+    }
+    match html.and_then(puzzle::example) {
+        Some(example) => match fs::write(example_path, example) {
+            Ok(_) => {
+                println!("Wrote example to \"{example_path}\"");
+            }
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        },
+        None => match create_file(example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{example_path}\"");
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
+        },
+    }
+}
+
+/// Formats an example answer as the expected value of a test.
+fn expected_answer(answer: Option<&String>) -> String {
+    match answer {
+        Some(answer) if answer.parse::<u64>().is_ok() => format!("Some({answer})"),
+        _ => "None".to_string(),
+    }
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
+    let day = args.day;
     let day_padded = format!("{day:02}");
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
-
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
+    let config = config();
+    let data_path = |dir: &Path, extension: &str| {
+        dir.join(format!("{day_padded}.{extension}"))
+            .to_string_lossy()
+            .into_owned()
     };
+    let input_path = data_path(&config.inputs_dir, "txt");
+    let example_path = data_path(&config.examples_dir, "txt");
+    let answers_path = data_path(&config.examples_dir, "answers");
+    let module_name = format!("day{day_padded}");
+    let module_path = format!("src/days/{module_name}.rs");
+    let bin_path = format!("src/bin/{day_padded}.rs");
+    let modules_path = "src/days.rs";
+    let puzzle_path = format!("puzzles/{day_padded}.md");
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    let html = match read_puzzle(&args) {
+        Ok(html) => Some(html),
+        Err(e) if args.puzzle_only => {
+            eprintln!("Failed to get puzzle: {e}");
+            process::exit(1);
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+            eprintln!("Failed to get puzzle, skipping description and example: {e}");
+            None
         }
+    };
+
+    if let Some(html) = &html {
+        write_puzzle(html, &puzzle_path, &answers_path);
+        record_puzzle_answers(html, day);
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    if !args.puzzle_only {
+        let example_answers = html
+            .as_deref()
+            .map(puzzle::example_answers)
+            .unwrap_or_default();
+
+        let template = match &config.template {
+            Some(path) => match fs::read_to_string(path) {
+                Ok(template) => template,
+                Err(e) => {
+                    eprintln!("Failed to read template \"{}\": {e}", path.display());
+                    process::exit(1);
+                }
+            },
+            None => MODULE_TEMPLATE.to_string(),
+        };
+
+        let mut file = match safe_create_file(&module_path) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("Failed to create module file: {e}");
+                process::exit(1);
+            }
+        };
+
+        match file.write_all(template.as_bytes()) {
+            Ok(_) => {
+                println!("Created module file \"{}\"", module_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to write module contents: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+
+        match register_module(modules_path, day) {
+            Ok(_) => {
+                println!("Registered module {module_name} in \"{modules_path}\"");
+            }
+            Err(e) => {
+                eprintln!("Failed to register module: {e}");
+                process::exit(1);
+            }
         }
-    }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+        let mut file = match safe_create_file(&bin_path) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("Failed to create bin file: {e}");
+                process::exit(1);
+            }
+        };
+
+        let bin = BIN_TEMPLATE
+            .replace("MODULE", &module_name)
+            .replace("DAY", &day.to_string())
+            .replace(
+                "PART_ONE_ANSWER",
+                &expected_answer(example_answers.first().and_then(Option::as_ref)),
+            )
+            .replace(
+                "PART_TWO_ANSWER",
+                &expected_answer(example_answers.get(1).and_then(Option::as_ref)),
+            );
+
+        match file.write_all(bin.as_bytes()) {
+            Ok(_) => {
+                println!("Created bin file \"{bin_path}\"");
+            }
+            Err(e) => {
+                eprintln!("Failed to write bin contents: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{input_path}\"");
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
     }
 
+    write_example(html.as_deref(), &example_path);
+
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if args.puzzle_only {
+        println!("🎄 Updated the puzzle of day {}.", day_padded);
+    } else {
+        println!("🎄 Type `cargo solve {}` to run your solution.", day_padded);
+    }
 }
@@ -0,0 +1,129 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Serves the solutions over HTTP: `POST /solve/{day}/{part}` with the input as body
+//! answers `{"day", "part", "variant", "answer", "elapsed"}`, `elapsed` in seconds.
+//!
+//! The query sets the parameters of the part, such as `?target_line=10` for day 15,
+//! and `variant` picks another variant than the first one.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+
+use adventofcode::batch::panic_message;
+use adventofcode::variant::{Answer, Params};
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+const DEFAULT_PORT: u16 = 3000;
+
+struct Args {
+    port: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        port: args
+            .opt_value_from_str(["-p", "--port"])?
+            .unwrap_or(DEFAULT_PORT),
+    })
+}
+
+/// Parses `/solve/{day}/{part}?name=value&...` into the day, the part and the query.
+fn parse_url(url: &str) -> Option<(u8, u8, Params)> {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let mut segments = path.strip_prefix("/solve/")?.split('/');
+    let day = segments.next()?.parse().ok()?;
+    let part = segments.next()?.parse().ok()?;
+    if segments.next().is_some() {
+        return None;
+    }
+    let params = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+        .collect();
+    Some((day, part, params))
+}
+
+fn error(status: u16, message: impl Into<String>) -> (u16, Value) {
+    (status, json!({ "error": message.into() }))
+}
+
+fn solve(request: &mut Request) -> (u16, Value) {
+    if *request.method() != Method::Post {
+        return error(405, "only POST is supported");
+    }
+    let Some((day, part, params)) = parse_url(request.url()) else {
+        return error(404, "expected /solve/{day}/{part}");
+    };
+    let Some(variants) = adventofcode::variants(day) else {
+        return error(404, format!("day {day} is not solved"));
+    };
+    let name = params.get("variant", String::new());
+    let Some(variant) = variants
+        .iter()
+        .filter(|variant| variant.part == part)
+        .find(|variant| name.is_empty() || variant.name == name)
+    else {
+        return error(404, format!("day {day} has no such part or variant"));
+    };
+
+    let mut input = String::new();
+    if request.as_reader().read_to_string(&mut input).is_err() {
+        return error(400, "the input must be UTF-8");
+    }
+
+    // solutions panic on invalid inputs and parameters.
+    match panic::catch_unwind(AssertUnwindSafe(|| variant.timed_with(&input, &params))) {
+        Ok((answer, elapsed)) => {
+            let answer = match answer {
+                Some(Answer::Number(n)) => json!(n),
+                Some(Answer::Text(text)) => json!(text),
+                None => Value::Null,
+            };
+            let body = json!({
+                "day": day,
+                "part": part,
+                "variant": variant.name,
+                "answer": answer,
+                "elapsed": elapsed.as_secs_f64(),
+            });
+            (200, body)
+        }
+        Err(payload) => error(422, panic_message(payload.as_ref())),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo serve --port 3000`");
+            process::exit(1);
+        }
+    };
+
+    let server = match Server::http(("127.0.0.1", args.port)) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("Failed to listen on port {}: {e}", args.port);
+            process::exit(1);
+        }
+    };
+    // the port is chosen by the system with `--port 0`.
+    println!("🎄 Listening on http://{}", server.server_addr());
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    for mut request in server.incoming_requests() {
+        let (status, body) = solve(&mut request);
+        let response = Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            eprintln!("Failed to respond: {e}");
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use adventofcode::config::config;
+use adventofcode::stars;
+use std::path::Path;
+use std::process;
+
+fn parse_args() -> Result<Option<i32>, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    args.opt_value_from_str(["-y", "--year"])
+}
+
+fn main() {
+    let year = match parse_args() {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let year = year.or(config().year);
+    match stars::update_readme(Path::new("README.md"), Path::new("submissions"), year) {
+        Ok(stars) => {
+            println!("🎄 Updated \"README.md\" with {stars} ⭐.");
+        }
+        Err(e) => {
+            eprintln!("Failed to update \"README.md\": {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use adventofcode::config::config;
+use adventofcode::stars;
+use adventofcode::submissions::{self, Submission, Verdict};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{self, Command};
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: Option<i16>,
+    answer: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let answer = args.opt_value_from_str(["-a", "--answer"])?;
+    Ok(Args {
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        year,
+        answer,
+    })
+}
+
+/// Runs the solution of `day` and returns its answer to `part`.
+fn solve(day_padded: &str, part: u8) -> Option<String> {
+    let cmd = Command::new("cargo")
+        .arg("run")
+        .args(&config().runner.cargo_args)
+        .args(["--bin", day_padded])
+        .output();
+    let output = match cmd {
+        Ok(cmd) if cmd.status.success() => String::from_utf8(cmd.stdout).unwrap(),
+        Ok(cmd) => {
+            io::stderr()
+                .write_all(&cmd.stderr)
+                .expect("could not write cmd stderr to pipe.");
+            eprintln!("Failed to run the solution of day {day_padded}.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to spawn cargo: {e}");
+            process::exit(1);
+        }
+    };
+    println!("{}", output.trim());
+    adventofcode::parse_answer(&output, part)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    let day_padded = format!("{:02}", args.day);
+    let submissions_dir = Path::new("submissions");
+
+    let answer = match args.answer.clone() {
+        Some(answer) => answer,
+        None => match solve(&day_padded, args.part) {
+            Some(answer) => answer,
+            None => {
+                eprintln!("Part {} of day {day_padded} is not solved.", args.part);
+                process::exit(1);
+            }
+        },
+    };
+
+    if answer.is_empty() || answer.contains('\n') {
+        eprintln!("Refusing to submit \"{answer}\": answers are single line.");
+        process::exit(1);
+    }
+
+    let previous = submissions::load(submissions_dir, args.day);
+    if let Err(refusal) = submissions::check(&previous, args.part, &answer) {
+        eprintln!("Refusing to submit {answer}: {refusal}.");
+        process::exit(1);
+    }
+
+    // check if aoc binary exists and is callable.
+    if Command::new("aoc").arg("-V").output().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    let mut cmd_args = vec![];
+
+    let year = args.year.map(i32::from).or(config().year);
+    if let Some(year) = year {
+        cmd_args.push("--year".into());
+        cmd_args.push(year.to_string());
+    }
+
+    if let Some(session_file) = config().session_file() {
+        cmd_args.push("--session-file".into());
+        cmd_args.push(session_file.to_string_lossy().to_string());
+    }
+
+    cmd_args.append(&mut vec![
+        "--day".into(),
+        args.day.to_string(),
+        "submit".into(),
+        args.part.to_string(),
+        answer.clone(),
+    ]);
+
+    println!("Submitting answer with >aoc {}", cmd_args.join(" "));
+
+    let response = match Command::new("aoc").args(cmd_args).output() {
+        Ok(cmd_output) => {
+            io::stdout()
+                .write_all(&cmd_output.stdout)
+                .expect("could not write cmd stdout to pipe.");
+            io::stderr()
+                .write_all(&cmd_output.stderr)
+                .expect("could not write cmd stderr to pipe.");
+            if !cmd_output.status.success() {
+                process::exit(1);
+            }
+            String::from_utf8_lossy(&cmd_output.stdout).into_owned()
+        }
+        Err(e) => {
+            eprintln!("failed to spawn aoc-cli: {e}");
+            process::exit(1);
+        }
+    };
+
+    let Some(verdict) = Verdict::from_response(&response) else {
+        eprintln!("The answer was not evaluated, nothing recorded.");
+        process::exit(1);
+    };
+
+    let submission = Submission {
+        part: args.part,
+        verdict,
+        answer,
+    };
+    let log_path = submissions::log_path(submissions_dir, args.day);
+    if let Err(e) = submissions::record(submissions_dir, args.day, &submission) {
+        eprintln!(
+            "Failed to record the submission to \"{}\": {e}",
+            log_path.display()
+        );
+        process::exit(1);
+    }
+
+    println!("---");
+    if verdict == Verdict::Correct {
+        println!("🎄 Part {} of day {day_padded} is solved!", args.part);
+        if let Err(e) = stars::update_readme(Path::new("README.md"), submissions_dir, year) {
+            eprintln!("Failed to update \"README.md\": {e}");
+        }
+    } else {
+        println!("Recorded {verdict} answer to \"{}\".", log_path.display());
+        process::exit(1);
+    }
+}
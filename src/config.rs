@@ -0,0 +1,218 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Settings shared by every tool, read from `aoc.toml` at the root of the repository.
+//!
+//! Every setting is optional, see `aoc.toml` for the defaults.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+use crate::aoc::current_event_year;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Year of the event, defaults to the current or last event.
+    pub year: Option<i32>,
+    pub inputs_dir: PathBuf,
+    pub examples_dir: PathBuf,
+    /// File holding the session cookie, `~/` is expanded to the home directory.
+    pub session_file: Option<PathBuf>,
+    /// Template of the solution modules `src/days/dayNN.rs` created by `cargo scaffold`.
+    pub template: Option<PathBuf>,
+    pub runner: Runner,
+    pub bench: Bench,
+    /// Commands of reference solutions by day, compared to ours by `cargo diff`.
+    pub references: BTreeMap<String, String>,
+    pub budgets: Budgets,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Runner {
+    /// Flags given to `cargo run` when running a solution.
+    pub cargo_args: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Bench {
+    /// Number of samples collected by each benchmark, at least 10.
+    pub iterations: usize,
+}
+
+/// Time budgets checked by `cargo all`, in milliseconds.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Budgets {
+    /// Target of the total time of every day.
+    pub total: Option<f64>,
+    /// Budgets of the parts by day, keyed by `5` or `05`.
+    pub days: BTreeMap<String, Vec<f64>>,
+}
+
+impl Budgets {
+    /// Budget of `part` of `day`, if it has one.
+    pub fn part(&self, day: u8, part: u8) -> Option<f64> {
+        let budgets = by_day(&self.days, day)?;
+        budgets.get(usize::from(part).checked_sub(1)?).copied()
+    }
+}
+
+fn by_day<T>(map: &BTreeMap<String, T>, day: u8) -> Option<&T> {
+    [day.to_string(), format!("{day:02}")]
+        .iter()
+        .find_map(|key| map.get(key))
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: None,
+            inputs_dir: PathBuf::from("src/inputs"),
+            examples_dir: PathBuf::from("src/examples"),
+            session_file: None,
+            template: None,
+            runner: Runner::default(),
+            bench: Bench::default(),
+            references: BTreeMap::new(),
+            budgets: Budgets::default(),
+        }
+    }
+}
+
+impl Default for Runner {
+    fn default() -> Self {
+        Runner {
+            cargo_args: vec!["--release".to_string()],
+        }
+    }
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Bench { iterations: 100 }
+    }
+}
+
+impl Config {
+    pub fn parse(toml: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(toml)
+    }
+
+    /// Reads the config at `path`, or returns the default config if there is none.
+    pub fn load(path: &Path) -> Result<Config, String> {
+        match fs::read_to_string(path) {
+            Ok(toml) => Config::parse(&toml).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// The configured year, or else the year of the current or last event.
+    pub fn year(&self) -> i32 {
+        self.year
+            .unwrap_or_else(|| current_event_year(SystemTime::now()))
+    }
+
+    /// Directory of `folder`: the inputs or examples directory, or else `src/{folder}`.
+    pub fn dir(&self, folder: &str) -> PathBuf {
+        match folder {
+            "inputs" => self.inputs_dir.clone(),
+            "examples" => self.examples_dir.clone(),
+            _ => Path::new("src").join(folder),
+        }
+    }
+
+    /// Command of the reference solution of `day`, keyed by `5` or `05`.
+    pub fn reference(&self, day: u8) -> Option<&str> {
+        by_day(&self.references, day).map(String::as_str)
+    }
+
+    pub fn session_file(&self) -> Option<PathBuf> {
+        let path = self.session_file.as_ref()?;
+        match path.strip_prefix("~") {
+            Ok(rest) => {
+                let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+                Some(Path::new(&home).join(rest))
+            }
+            Err(_) => Some(path.clone()),
+        }
+    }
+}
+
+/// The config of the current directory, loaded once.
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| {
+        Config::load(Path::new(CONFIG_FILE)).unwrap_or_else(|e| panic!("invalid config {e}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+
+        let config = Config::parse(
+            r#"
+year = 2021
+inputs_dir = "inputs"
+session_file = "~/.session"
+
+[runner]
+cargo_args = []
+
+[bench]
+iterations = 10
+
+[references]
+5 = "python3 day05.py"
+07 = "./day07 {part}"
+
+[budgets]
+total = 1000
+
+[budgets.days]
+05 = [0.5, 1.5]
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.year(), 2021);
+        assert_eq!(config.dir("inputs"), Path::new("inputs"));
+        assert_eq!(config.dir("examples"), Path::new("src/examples"));
+        assert_eq!(config.dir("other"), Path::new("src/other"));
+        assert!(config.session_file().unwrap().ends_with(".session"));
+        assert!(config.runner.cargo_args.is_empty());
+        assert_eq!(config.bench.iterations, 10);
+        assert_eq!(config.reference(5), Some("python3 day05.py"));
+        assert_eq!(config.reference(7), Some("./day07 {part}"));
+        assert_eq!(config.reference(1), None);
+        assert_eq!(config.budgets.total, Some(1000.0));
+        assert_eq!(config.budgets.part(5, 2), Some(1.5));
+        assert_eq!(config.budgets.part(5, 3), None);
+        assert_eq!(config.budgets.part(6, 1), None);
+
+        assert!(Config::parse("yaer = 2021").is_err());
+    }
+
+    #[test]
+    fn test_repository_config() {
+        let config = Config::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join(CONFIG_FILE));
+        assert_eq!(config.unwrap().year, Some(2022));
+    }
+}
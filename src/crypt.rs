@@ -0,0 +1,260 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Encryption of puzzle inputs, so that they can be committed without being published.
+//!
+//! An encrypted input `NN.txt.enc` is the XChaCha20-Poly1305 encryption of `NN.txt`
+//! with a 256 bits key, shared as 64 hexadecimal digits.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, error};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+
+/// Environment variable holding the key.
+pub const KEY_VAR: &str = "ADVENT_OF_CODE_INPUT_KEY";
+
+/// Name of the file, in the home directory, holding the key when [`KEY_VAR`] is not set.
+pub const KEY_FILE: &str = ".adventofcode.input-key";
+
+/// Extension appended to the name of encrypted files.
+pub const EXTENSION: &str = "enc";
+
+const MAGIC: &[u8] = b"aoc-enc1";
+const NONCE_LEN: usize = 24;
+
+#[derive(Debug)]
+pub enum CryptError {
+    MissingKey,
+    InvalidKey,
+    InvalidFile,
+    Io(io::Error),
+}
+
+impl fmt::Display for CryptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CryptError::MissingKey => write!(
+                f,
+                "no key found, set {KEY_VAR} or write the key to ~/{KEY_FILE}"
+            ),
+            CryptError::InvalidKey => write!(f, "the key must be 64 hexadecimal digits"),
+            CryptError::InvalidFile => {
+                write!(f, "the file is not encrypted with this key or is corrupted")
+            }
+            CryptError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for CryptError {}
+
+impl From<io::Error> for CryptError {
+    fn from(e: io::Error) -> Self {
+        CryptError::Io(e)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    /// Generates a new random key.
+    pub fn generate() -> Key {
+        Key(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    /// Reads the key from [`KEY_VAR`], or else from [`KEY_FILE`].
+    pub fn load() -> Result<Key, CryptError> {
+        if let Ok(key) = env::var(KEY_VAR) {
+            return key.parse();
+        }
+        let home = env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .ok_or(CryptError::MissingKey)?;
+        match fs::read_to_string(Path::new(&home).join(KEY_FILE)) {
+            Ok(key) => key.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(CryptError::MissingKey),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+impl FromStr for Key {
+    type Err = CryptError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.len() != 64 || !s.is_ascii() {
+            return Err(CryptError::InvalidKey);
+        }
+        let mut key = [0; 32];
+        for (byte, digits) in key.iter_mut().zip(s.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).map_err(|_| CryptError::InvalidKey)?;
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| CryptError::InvalidKey)?;
+        }
+        Ok(Key(key))
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, plaintext)
+        .expect("plaintext is too long");
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, CryptError> {
+    let data = data.strip_prefix(MAGIC).ok_or(CryptError::InvalidFile)?;
+    if data.len() < NONCE_LEN {
+        return Err(CryptError::InvalidFile);
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    key.cipher()
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| CryptError::InvalidFile)
+}
+
+/// Path of the encrypted version of `path`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(EXTENSION);
+    path.into()
+}
+
+/// Reads and decrypts the encrypted file at `path`.
+pub fn read_encrypted(path: &Path) -> Result<String, CryptError> {
+    let plaintext = decrypt(&Key::load()?, &fs::read(path)?)?;
+    String::from_utf8(plaintext).map_err(|_| CryptError::InvalidFile)
+}
+
+/// Encrypts every `.txt` file of `dir`, returning the paths of the encrypted files.
+pub fn encrypt_dir(key: &Key, dir: &Path) -> Result<Vec<PathBuf>, CryptError> {
+    let mut written = Vec::new();
+    for path in files_with_extension(dir, "txt")? {
+        let encrypted = encrypted_path(&path);
+        // Keep the existing file if it is up to date, to not rewrite it with a new nonce.
+        let plaintext = fs::read(&path)?;
+        let up_to_date = fs::read(&encrypted)
+            .ok()
+            .and_then(|data| decrypt(key, &data).ok())
+            .is_some_and(|existing| existing == plaintext);
+        if !up_to_date {
+            fs::write(&encrypted, encrypt(key, &plaintext))?;
+            written.push(encrypted);
+        }
+    }
+    Ok(written)
+}
+
+/// Decrypts every `.enc` file of `dir`, returning the paths of the decrypted files.
+pub fn decrypt_dir(key: &Key, dir: &Path) -> Result<Vec<PathBuf>, CryptError> {
+    let mut written = Vec::new();
+    for path in files_with_extension(dir, EXTENSION)? {
+        let decrypted = path.with_extension("");
+        fs::write(&decrypted, decrypt(key, &fs::read(&path)?)?)?;
+        written.push(decrypted);
+    }
+    Ok(written)
+}
+
+fn files_with_extension(dir: &Path, extension: &str) -> io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == extension) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn test_key() {
+        let key: Key = KEY.parse().unwrap();
+        assert_eq!(key.to_string(), KEY);
+        assert!(matches!("00ff".parse::<Key>(), Err(CryptError::InvalidKey)));
+        assert!(matches!(
+            KEY.replace('0', "g").parse::<Key>(),
+            Err(CryptError::InvalidKey)
+        ));
+        assert_ne!(Key::generate(), Key::generate());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let key: Key = KEY.parse().unwrap();
+        let encrypted = encrypt(&key, b"1000\n2000\n");
+        assert_ne!(encrypted, encrypt(&key, b"1000\n2000\n"));
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), b"1000\n2000\n");
+
+        let other_key = Key::generate();
+        assert!(matches!(
+            decrypt(&other_key, &encrypted),
+            Err(CryptError::InvalidFile)
+        ));
+
+        let mut tampered = encrypted.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            decrypt(&key, &tampered),
+            Err(CryptError::InvalidFile)
+        ));
+        assert!(matches!(
+            decrypt(&key, b"1000\n2000\n"),
+            Err(CryptError::InvalidFile)
+        ));
+    }
+
+    #[test]
+    fn test_dir() {
+        let key: Key = KEY.parse().unwrap();
+        let dir = env::temp_dir().join(format!("adventofcode-crypt-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("01.txt"), "1000\n").unwrap();
+        fs::write(dir.join(".keep"), "").unwrap();
+
+        assert_eq!(
+            encrypt_dir(&key, &dir).unwrap(),
+            vec![dir.join("01.txt.enc")]
+        );
+        assert_eq!(encrypt_dir(&key, &dir).unwrap(), Vec::<PathBuf>::new());
+
+        fs::remove_file(dir.join("01.txt")).unwrap();
+        assert_eq!(decrypt_dir(&key, &dir).unwrap(), vec![dir.join("01.txt")]);
+        assert_eq!(fs::read_to_string(dir.join("01.txt")).unwrap(), "1000\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
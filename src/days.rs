@@ -0,0 +1,48 @@
+//! Solutions of each day, with their parsers and models.
+
+use crate::variant::Variant;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+
+/// Variants of the solution of `day`, if there is one.
+pub fn variants(day: u8) -> Option<Vec<Variant>> {
+    let variants = match day {
+        1 => day01::variants(),
+        2 => day02::variants(),
+        3 => day03::variants(),
+        4 => day04::variants(),
+        5 => day05::variants(),
+        6 => day06::variants(),
+        7 => day07::variants(),
+        8 => day08::variants(),
+        9 => day09::variants(),
+        10 => day10::variants(),
+        11 => day11::variants(),
+        12 => day12::variants(),
+        13 => day13::variants(),
+        14 => day14::variants(),
+        15 => day15::variants(),
+        16 => day16::variants(),
+        17 => day17::variants(),
+        18 => day18::variants(),
+        _ => return None,
+    };
+    Some(variants)
+}
@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+
+use crate::helpers::unchecked;
+#[cfg(not(feature = "safe"))]
+use crate::helpers::StrExt;
+use crate::variant::Variant;
+use byte_set::ByteSet;
+use itertools::Itertools;
+
+#[inline]
+pub fn item_priority(item: u8) -> u32 {
+    debug_assert!(item.is_ascii_alphabetic());
+    let value = if item < b'a' {
+        item - const { b'A' - 27 }
+    } else {
+        item - const { b'a' - 1 }
+    };
+    value as u32
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let value = input
+        .lines()
+        .map(|line| {
+            let item_count = line.len();
+            debug_assert_eq!(item_count % 2, 0);
+            let (a, b) = line.split_at(item_count / 2);
+            debug_assert_eq!(a.len(), b.len());
+            let a = HashSet::<_>::from_iter(a.as_bytes());
+            let b = HashSet::from_iter(b.as_bytes());
+
+            let item = a.intersection(&b).exactly_one().unwrap();
+
+            item_priority(**item)
+        })
+        .sum();
+
+    Some(value)
+}
+
+pub fn part_one_2(input: &str) -> Option<u32> {
+    let value = input
+        .lines()
+        .map(|line| {
+            let item_count = line.len();
+            debug_assert_eq!(item_count % 2, 0);
+            let mid = item_count / 2;
+            // SAFETY: `mid` is on a char boundary : the input only contains
+            // 1 byte length character ('a' through 'z' and 'A' through 'Z');
+            let (a, b) = unchecked!(line.split_at_unchecked(mid), line.split_at(mid));
+            debug_assert_eq!(a.len(), b.len());
+            let a = HashSet::<_>::from_iter(a.as_bytes());
+            let b = HashSet::from_iter(b.as_bytes());
+
+            let item = a.intersection(&b).exactly_one().unwrap();
+
+            item_priority(**item)
+        })
+        .sum();
+
+    Some(value)
+}
+
+pub fn part_one_3(input: &str) -> Option<u32> {
+    let value = input
+        .lines()
+        .map(|line| {
+            let item_count = line.len();
+            debug_assert_eq!(item_count % 2, 0);
+            let mid = item_count / 2;
+            // SAFETY: `mid` is on a char boundary : the input only contains
+            // 1 byte length character ('a' through 'z' and 'A' through 'Z');
+            let (a, b) = unchecked!(line.split_at_unchecked(mid), line.split_at(mid));
+            debug_assert_eq!(a.len(), b.len());
+            let a = HashSet::<_>::from_iter(a.bytes());
+            let b = HashSet::from_iter(b.bytes());
+
+            let item = a.intersection(&b).exactly_one().unwrap();
+
+            item_priority(*item)
+        })
+        .sum();
+
+    Some(value)
+}
+
+pub fn part_one_4(input: &str) -> Option<u32> {
+    let value = input
+        .lines()
+        .map(|line| {
+            let item_count = line.len();
+            debug_assert_eq!(item_count % 2, 0);
+            let mid = item_count / 2;
+            // SAFETY: `mid` is on a char boundary : the input only contains
+            // 1 byte length character ('a' through 'z' and 'A' through 'Z');
+            let (a, b) = unchecked!(line.split_at_unchecked(mid), line.split_at(mid));
+            debug_assert_eq!(a.len(), b.len());
+
+            let a = ByteSet::from_iter(a.bytes());
+            let b = ByteSet::from_iter(b.bytes());
+
+            let intersection = a.intersection(b);
+            debug_assert_eq!(intersection.len(), 1);
+            let item = intersection.first().unwrap();
+
+            item_priority(item)
+        })
+        .sum();
+
+    Some(value)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let value = input
+        .lines()
+        .tuples()
+        .map(|(a, b, c)| {
+            let a = HashSet::<_>::from_iter(a.as_bytes());
+            let b = HashSet::from_iter(b.as_bytes());
+            let c = HashSet::from_iter(c.as_bytes());
+
+            let a_b_common = a.intersection(&b).copied().collect::<HashSet<_>>();
+            let common_item = a_b_common.intersection(&c).exactly_one().unwrap();
+
+            item_priority(**common_item)
+        })
+        .sum();
+
+    Some(value)
+}
+
+pub fn part_two_2(input: &str) -> Option<u32> {
+    let value = input
+        .lines()
+        .tuples()
+        .map(|(a, b, c)| {
+            let a = HashSet::<_>::from_iter(a.bytes());
+            let b = HashSet::from_iter(b.bytes());
+            let c = HashSet::from_iter(c.bytes());
+
+            let a_b_common = a.intersection(&b).copied().collect::<HashSet<_>>();
+            let common_item = a_b_common.intersection(&c).exactly_one().unwrap();
+
+            item_priority(*common_item)
+        })
+        .sum();
+
+    Some(value)
+}
+
+pub fn part_two_3(input: &str) -> Option<u32> {
+    let value = input
+        .lines()
+        .tuples()
+        .map(|(a, b, c)| {
+            let a = ByteSet::from_iter(a.bytes());
+            let b = ByteSet::from_iter(b.bytes());
+            let c = ByteSet::from_iter(c.bytes());
+
+            let common_item = a.intersection(b).intersection(c);
+            debug_assert_eq!(common_item.len(), 1);
+            let item = common_item.first().unwrap();
+
+            item_priority(item)
+        })
+        .sum();
+
+    Some(value)
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        Variant::new(1, "part_one_4", part_one_4),
+        Variant::new(1, "part_one", part_one),
+        Variant::new(1, "part_one_2", part_one_2),
+        Variant::new(1, "part_one_3", part_one_3),
+        Variant::new(2, "part_two_3", part_two_3),
+        Variant::new(2, "part_two", part_two),
+        Variant::new(2, "part_two_2", part_two_2),
+    ]
+}
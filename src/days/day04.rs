@@ -0,0 +1,49 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::variant::Variant;
+use tuple::Map;
+
+pub fn parse_line(line: &str) -> (RangeInclusive<u32>, RangeInclusive<u32>) {
+    line.split_once(',').unwrap().map(|range| {
+        let (start, end) = range
+            .split_once('-')
+            .unwrap()
+            .map(|x| FromStr::from_str(x).unwrap());
+        start..=end
+    })
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let overlap_count = input
+        .lines()
+        .filter(|line| {
+            let (a, b) = parse_line(line);
+            (a.contains(b.start()) && a.contains(b.end()))
+                || (b.contains(a.start()) && b.contains(a.end()))
+        })
+        .count();
+
+    Some(overlap_count as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let overlap_count = input
+        .lines()
+        .filter(|line| {
+            let (a, b) = parse_line(line);
+            a.contains(b.start())
+                || a.contains(b.end())
+                || b.contains(a.start())
+                || b.contains(a.end())
+        })
+        .count();
+
+    Some(overlap_count as u32)
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        Variant::new(1, "part_one", part_one),
+        Variant::new(2, "part_two", part_two),
+    ]
+}
@@ -0,0 +1,116 @@
+use std::str::FromStr;
+
+use crate::variant::Variant;
+use itertools::Itertools;
+use tuple::Map;
+
+#[derive(Debug, Default, Clone)]
+pub struct Stack {
+    pub crates: Vec<u8>,
+}
+
+impl Stack {
+    pub fn add_one(&mut self, crate_: u8) {
+        self.crates.push(crate_)
+    }
+
+    pub fn add(&mut self, crates: impl IntoIterator<Item = u8>) {
+        self.crates.extend(crates);
+    }
+
+    pub fn take(&mut self, amount: usize) -> std::vec::Drain<'_, u8> {
+        self.crates.drain(self.crates.len() - amount..)
+    }
+
+    pub fn top(&self) -> u8 {
+        *self.crates.last().unwrap()
+    }
+}
+
+pub struct Instruction {
+    pub amount: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+pub fn parse_input(input: &str) -> (Vec<Stack>, impl Iterator<Item = Instruction> + '_) {
+    let mut lines = input.lines();
+
+    let crate_lines = lines
+        .by_ref()
+        // The crate and instruction sections are separated by an empty line
+        .take_while(|line| !line.is_empty())
+        // The last line of crate section is the index of the stacks
+        // this is useless, so we drop this line.
+        .filter(|line| line.as_bytes()[1] != b'1')
+        .map(|line| {
+            line.as_bytes()
+                .iter()
+                .copied()
+                .skip(1)
+                .step_by(4)
+                .map(|letter| (letter != b' ').then_some(letter))
+        })
+        // Collect to a vec to be able to iterate from the last element
+        .collect_vec();
+
+    let mut stacks = {
+        let stack_count = crate_lines.first().unwrap().len();
+        vec![Stack::default(); stack_count]
+    };
+
+    for line in crate_lines.into_iter().rev() {
+        for (i, letter) in line.enumerate() {
+            if let Some(letter) = letter {
+                stacks[i].add_one(letter);
+            }
+        }
+    }
+
+    let instructions = lines.map(|line| {
+        let (amount, from, to) = line
+            .split_ascii_whitespace()
+            .skip(1)
+            .step_by(2)
+            .collect_tuple::<(_, _, _)>()
+            .unwrap()
+            .map(|x| FromStr::from_str(x).unwrap());
+        Instruction {
+            amount,
+            // in input, `from` and `to` are base 1
+            from: from - 1,
+            to: to - 1,
+        }
+    });
+
+    (stacks, instructions)
+}
+
+pub fn part_one(input: &str) -> Option<String> {
+    let (mut stacks, instructions) = parse_input(input);
+    for Instruction { amount, from, to } in instructions {
+        let [from, to] = stacks.get_disjoint_mut([from, to]).unwrap();
+        to.add(from.take(amount).rev());
+    }
+    Some(String::from_iter(
+        stacks.into_iter().map(|stack| stack.top() as char),
+    ))
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let (mut stacks, instructions) = parse_input(input);
+    for Instruction { amount, from, to } in instructions {
+        let [from, to] = stacks.get_disjoint_mut([from, to]).unwrap();
+        to.add(from.take(amount));
+    }
+    Some(String::from_iter(
+        stacks.into_iter().map(|stack| stack.top() as char),
+    ))
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        Variant::new(1, "part_one", part_one),
+        Variant::new(2, "part_two", part_two),
+    ]
+}
@@ -0,0 +1,29 @@
+use std::collections::HashSet;
+
+use crate::variant::Variant;
+use itertools::Itertools;
+
+pub fn part_one(input: &str) -> Option<usize> {
+    for (i, (a, b, c, d)) in input.chars().tuple_windows().enumerate() {
+        if a != b && a != c && a != d && b != c && b != d && c != d {
+            return Some(i + 4);
+        }
+    }
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    for (i, x) in input.as_bytes().windows(14).enumerate() {
+        if HashSet::<_>::from_iter(x).len() == 14 {
+            return Some(14 + i);
+        }
+    }
+    unreachable!()
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        Variant::new(1, "part_one", part_one),
+        Variant::new(2, "part_two", part_two),
+    ]
+}
@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use crate::variant::Variant;
+
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+pub fn parse_intstruction(input: &str) -> impl Iterator<Item = Instruction> + '_ {
+    input.lines().map(|line| match line.as_bytes()[0] {
+        b'n' => Instruction::Noop,
+        b'a' => {
+            let value = FromStr::from_str(&line[5..]).unwrap();
+            Instruction::Addx(value)
+        }
+        _ => unreachable!(),
+    })
+}
+
+pub fn part_one(input: &str) -> Option<i32> {
+    let instructions = parse_intstruction(input);
+    let mut signal_strengths = Vec::with_capacity(6);
+    let mut x = 1;
+    let mut cycle = 1;
+
+    macro_rules! increment_cycle {
+        () => {{
+            cycle += 1;
+            if (cycle - 20) % 40 == 0 {
+                signal_strengths.push(cycle * x);
+            }
+        }};
+    }
+
+    for inst in instructions {
+        match inst {
+            Instruction::Noop => increment_cycle!(),
+            Instruction::Addx(value) => {
+                increment_cycle!();
+                x += value;
+                increment_cycle!();
+            }
+        }
+    }
+
+    debug_assert_eq!(signal_strengths.len(), 6);
+
+    Some(signal_strengths.into_iter().sum())
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    const SCREEN_PIXELS_COUNT: usize = 40 * 6;
+
+    let instructions = parse_intstruction(input);
+    let mut x = 1;
+    let mut cycle = 0;
+
+    let mut screen = String::with_capacity(SCREEN_PIXELS_COUNT);
+    macro_rules! increment_cycle {
+        () => {{
+            let pixel = if (x - 1..=x + 1).contains(&(cycle % 40)) {
+                '#'
+            } else {
+                '.'
+            };
+            screen.push(pixel);
+            cycle += 1;
+        }};
+    }
+
+    for inst in instructions {
+        match inst {
+            Instruction::Noop => increment_cycle!(),
+            Instruction::Addx(value) => {
+                increment_cycle!();
+                increment_cycle!();
+                x += value;
+            }
+        }
+    }
+
+    debug_assert_eq!(screen.len(), SCREEN_PIXELS_COUNT);
+
+    screen.reserve(5);
+
+    for i in 0..5 {
+        screen.insert((i + 1) * 40 + i, '\n');
+    }
+
+    Some(screen)
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        Variant::new(1, "part_one", part_one),
+        Variant::new(2, "part_two", part_two),
+    ]
+}
@@ -0,0 +1,199 @@
+#[cfg(feature = "safe")]
+use std::mem;
+use std::{cmp::Reverse, ops::Mul, str::FromStr};
+
+use crate::helpers::unchecked;
+use crate::variant::Variant;
+use itertools::Itertools;
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Item(pub u64);
+
+pub enum Operation {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+pub struct Monkey {
+    pub items: Vec<Item>,
+    pub operation: Operation,
+    pub test: u64,
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+/// Parses the monkeys of `input`.
+///
+/// Panics unless there are at least 2 monkeys, each throwing to other existing ones.
+pub fn parse_monkeys(input: &str) -> Vec<Monkey> {
+    const STARTING_ITEMS: &str = "  Starting items: ";
+    const OPERATION: &str = "  Operation: new = old ";
+    const TEST: &str = "  Test: divisible by ";
+    const IF_TRUE: &str = "    If true: throw to monkey ";
+    const IF_FALSE: &str = "    If false: throw to monkey ";
+
+    let monkeys = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .tuples()
+        .map(|(_, starting_items, operation, test, if_true, if_false)| {
+            let items = starting_items[STARTING_ITEMS.len()..]
+                .split(", ")
+                .map(FromStr::from_str)
+                .map(Result::unwrap)
+                .map(Item)
+                .collect_vec();
+
+            let operation = {
+                let operator = operation.as_bytes()[OPERATION.len()];
+                let rhs = &operation[OPERATION.len() + 2..];
+                match FromStr::from_str(rhs) {
+                    Ok(value) => match operator {
+                        b'+' => Operation::Add(value),
+                        b'*' => Operation::Mul(value),
+                        _ => unreachable!(),
+                    },
+                    Err(_) => Operation::Square,
+                }
+            };
+
+            let test = FromStr::from_str(&test[TEST.len()..]).unwrap();
+            let if_true = FromStr::from_str(&if_true[IF_TRUE.len()..]).unwrap();
+            let if_false = FromStr::from_str(&if_false[IF_FALSE.len()..]).unwrap();
+
+            Monkey {
+                items,
+                operation,
+                test,
+                if_true,
+                if_false,
+            }
+        })
+        .collect_vec();
+
+    assert!(monkeys.len() >= 2, "there must be at least 2 monkeys");
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for recipient in [monkey.if_true, monkey.if_false] {
+            assert!(
+                recipient < monkeys.len() && recipient != i,
+                "monkey {i} cannot throw to monkey {recipient}"
+            );
+        }
+    }
+    monkeys
+}
+
+/// Throws the items of monkey `i` to the others, with their worry level lowered by
+/// `relief`, and returns how many items it inspected.
+#[cfg(not(feature = "safe"))]
+#[inline]
+fn throw_items(monkeys: &mut [Monkey], i: usize, relief: impl Fn(u64) -> u64) -> usize {
+    let len = monkeys.len();
+    assert!(i < len);
+    // borrowing the monkeys through one pointer keeps the references to each one valid.
+    let monkeys = monkeys.as_mut_ptr();
+    // Safety: `i` is in bounds of `monkeys`.
+    // Safety: We garentees the reference is unique.
+    let monkey = unsafe { &mut *monkeys.add(i) };
+    let inspected = monkey.items.len();
+
+    for item in monkey.items.drain(..) {
+        let item = match monkey.operation {
+            Operation::Add(x) => item.0 + x,
+            Operation::Mul(x) => item.0 * x,
+            Operation::Square => item.0 * item.0,
+        };
+        let item = relief(item);
+        let recipient = if item % monkey.test == 0 {
+            monkey.if_true
+        } else {
+            monkey.if_false
+        };
+
+        assert!(recipient < len && recipient != i);
+        // Safety: `recipient` is in bounds of `monkeys`, and is not `i` so the reference
+        // does not alias `monkey`.
+        let recipient = unsafe { &mut *monkeys.add(recipient) };
+        recipient.items.push(Item(item));
+    }
+    inspected
+}
+
+#[cfg(feature = "safe")]
+#[inline]
+fn throw_items(monkeys: &mut [Monkey], i: usize, relief: impl Fn(u64) -> u64) -> usize {
+    let items = mem::take(&mut monkeys[i].items);
+    let inspected = items.len();
+
+    for item in items {
+        let monkey = &monkeys[i];
+        let item = match monkey.operation {
+            Operation::Add(x) => item.0 + x,
+            Operation::Mul(x) => item.0 * x,
+            Operation::Square => item.0 * item.0,
+        };
+        let item = relief(item);
+        let recipient = if item % monkey.test == 0 {
+            monkey.if_true
+        } else {
+            monkey.if_false
+        };
+
+        monkeys[recipient].items.push(Item(item));
+    }
+    inspected
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let mut monkeys = parse_monkeys(input);
+    let mut inspect_counts = vec![0; monkeys.len()];
+
+    for _ in 0..20 {
+        for (i, count) in inspect_counts.iter_mut().enumerate() {
+            *count += throw_items(&mut monkeys, i, |item| item / 3);
+        }
+    }
+
+    inspect_counts.sort_unstable_by_key(|&num| Reverse(num));
+
+    // Safety: there is at least 2 monkeys.
+    let business = unchecked!(
+        inspect_counts.get_unchecked(0) * inspect_counts.get_unchecked(1),
+        inspect_counts[0] * inspect_counts[1]
+    );
+    Some(business)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut monkeys = parse_monkeys(input);
+    let worry_max = monkeys
+        .iter()
+        .map(|monkey| monkey.test)
+        .reduce(Mul::mul)
+        .unwrap();
+    let mut inspect_counts = vec![0; monkeys.len()];
+
+    for _ in 0..10000 {
+        for (i, count) in inspect_counts.iter_mut().enumerate() {
+            *count += throw_items(&mut monkeys, i, |item| item % worry_max);
+        }
+    }
+
+    inspect_counts.sort_unstable_by_key(|&num| Reverse(num));
+
+    // Safety: there is at least 2 monkeys.
+    let business = unchecked!(
+        inspect_counts.get_unchecked(0) * inspect_counts.get_unchecked(1),
+        inspect_counts[0] * inspect_counts[1]
+    );
+    Some(business)
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        Variant::new(1, "part_one", part_one),
+        Variant::new(2, "part_two", part_two),
+    ]
+}
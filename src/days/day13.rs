@@ -0,0 +1,142 @@
+use crate::helpers::parser::ParseError;
+use crate::variant::Variant;
+use itertools::Itertools;
+use nom::Finish;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Item {
+    Number(u32),
+    List(Box<[Item]>),
+}
+
+pub mod parser {
+    use crate::helpers;
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        combinator::map,
+        error::{Error, ErrorKind},
+        multi::separated_list0,
+        sequence::delimited,
+        IResult,
+    };
+
+    use super::Item;
+
+    /// Deepest nesting of lists, beyond which packets are rejected rather than
+    /// overflowing the stack.
+    pub const MAX_DEPTH: usize = 256;
+
+    pub fn item(input: &str) -> IResult<&str, Item> {
+        nested_item(input, 0)
+    }
+
+    fn nested_item(input: &str, depth: usize) -> IResult<&str, Item> {
+        alt((
+            map(|input| item_list(input, depth), Item::List),
+            map(item_number, Item::Number),
+        ))(input)
+    }
+
+    pub fn item_number(input: &str) -> IResult<&str, u32> {
+        helpers::parser::decimal_value(input)
+    }
+
+    /// Parses a list nested in `depth` others.
+    pub fn item_list(input: &str, depth: usize) -> IResult<&str, Box<[Item]>> {
+        if depth >= MAX_DEPTH && input.starts_with('[') {
+            return Err(nom::Err::Failure(Error::new(input, ErrorKind::TooLarge)));
+        }
+        map(
+            delimited(
+                tag("["),
+                separated_list0(tag(","), |input| nested_item(input, depth + 1)),
+                tag("]"),
+            ),
+            |items| items.into_boxed_slice(),
+        )(input)
+    }
+}
+
+pub fn parse_input(input: &str) -> impl Iterator<Item = Result<Item, ParseError<'_>>> + '_ {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parser::item(line).finish().map(|(_, item)| item))
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        fn cmp_list(a: &[Item], b: &[Item]) -> std::cmp::Ordering {
+            if let Some(cmp) =
+                a.iter().zip(b).map(|(a, b)| Ord::cmp(a, b)).find(|cmp| {
+                    matches!(cmp, std::cmp::Ordering::Less | std::cmp::Ordering::Greater)
+                })
+            {
+                cmp
+            } else {
+                Ord::cmp(&a.len(), &b.len())
+            }
+        }
+
+        match (self, other) {
+            (Item::Number(a), Item::Number(b)) => a.cmp(b),
+            (a @ Item::Number(_), Item::List(b)) => cmp_list(std::slice::from_ref(a), b),
+            (Item::List(a), b @ Item::Number(_)) => cmp_list(a, std::slice::from_ref(b)),
+            (Item::List(a), Item::List(b)) => cmp_list(a, b),
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let count = parse_input(input)
+        .map(|item| item.expect("invalid packet"))
+        .tuples()
+        .map(|(a, b)| Ord::cmp(&a, &b))
+        .enumerate()
+        .filter(|(_, cmp)| matches!(cmp, std::cmp::Ordering::Less))
+        .map(|(i, _)| i + 1)
+        .sum();
+
+    Some(count)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let a = Item::List(vec![Item::List(vec![Item::Number(2)].into())].into());
+    let b = Item::List(vec![Item::List(vec![Item::Number(6)].into())].into());
+
+    let items = parse_input(input)
+        .map(|item| item.expect("invalid packet"))
+        .chain([a.clone(), b.clone()])
+        .sorted();
+
+    let mut pos_a = None;
+    let mut pos_b = None;
+
+    for (i, item) in items.enumerate() {
+        if pos_a.is_none() && a == item {
+            pos_a = Some(i + 1);
+        }
+        if pos_b.is_none() && b == item {
+            pos_b = Some(i + 1);
+        }
+        if pos_a.is_some() && pos_b.is_some() {
+            break;
+        }
+    }
+
+    Some(pos_a.unwrap() * pos_b.unwrap())
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        Variant::new(1, "part_one", part_one),
+        Variant::new(2, "part_two", part_two),
+    ]
+}
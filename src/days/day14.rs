@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+use crate::helpers::{parser::ParseError, range};
+use crate::variant::Variant;
+use itertools::Itertools;
+use nom::{error::ErrorKind, Finish};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub fn down(self) -> Pos {
+        Pos {
+            x: self.x,
+            y: self.y + 1,
+        }
+    }
+
+    pub fn down_left(self) -> Pos {
+        Pos {
+            x: self.x - 1,
+            y: self.y + 1,
+        }
+    }
+
+    pub fn down_right(self) -> Pos {
+        Pos {
+            x: self.x + 1,
+            y: self.y + 1,
+        }
+    }
+}
+
+pub mod parser {
+    use super::Pos;
+
+    use crate::helpers::parser::decimal_value;
+    use nom::{
+        bytes::complete::tag, combinator::map, multi::separated_list1, sequence::separated_pair,
+        IResult,
+    };
+
+    pub fn line(input: &str) -> IResult<&str, Vec<Pos>> {
+        separated_list1(tag(" -> "), pos)(input)
+    }
+
+    pub fn pos(input: &str) -> IResult<&str, Pos> {
+        map(
+            separated_pair(decimal_value, tag(","), decimal_value),
+            |(x, y)| Pos { x, y },
+        )(input)
+    }
+}
+
+/// Parses the paths of rock, which must be horizontal or vertical.
+pub fn parse_paths(input: &str) -> Result<Vec<Vec<Pos>>, ParseError<'_>> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, points) = parser::line(line).finish()?;
+            let aligned = points
+                .iter()
+                .tuple_windows()
+                .all(|(a, b)| a.x == b.x || a.y == b.y);
+            if !aligned {
+                return Err(ParseError::new(line, ErrorKind::Verify));
+            }
+            Ok(points)
+        })
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<HashSet<Pos>, ParseError<'_>> {
+    let walls = parse_paths(input)?
+        .into_iter()
+        .flat_map(|points| {
+            points
+                .into_iter()
+                .tuple_windows()
+                .flat_map(|(a, b)| -> Box<dyn Iterator<Item = Pos>> {
+                    if a.x == b.x {
+                        let x = a.x;
+                        Box::new(range(a.y, b.y).map(move |y| Pos { x, y }))
+                    } else {
+                        let y = a.y;
+                        Box::new(range(a.x, b.x).map(move |x| Pos { x, y }))
+                    }
+                })
+        })
+        .collect();
+    Ok(walls)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut walls = parse_input(input).expect("invalid rock paths");
+
+    let void_y = walls.iter().map(|Pos { x: _, y }| y).max().unwrap() + 1;
+
+    const SAND_SOURCE_POS: Pos = Pos { x: 500, y: 0 };
+
+    let mut rested_sand = 0;
+
+    'outer: loop {
+        let mut sand_pos = SAND_SOURCE_POS;
+        loop {
+            if sand_pos.y == void_y {
+                break 'outer;
+            }
+            if !walls.contains(&sand_pos.down()) {
+                sand_pos = sand_pos.down();
+                continue;
+            }
+            if !walls.contains(&sand_pos.down_left()) {
+                sand_pos = sand_pos.down_left();
+                continue;
+            }
+            if !walls.contains(&sand_pos.down_right()) {
+                sand_pos = sand_pos.down_right();
+                continue;
+            }
+            walls.insert(sand_pos);
+            rested_sand += 1;
+            break;
+        }
+    }
+
+    Some(rested_sand)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut walls = parse_input(input).expect("invalid rock paths");
+
+    let floor_y = walls.iter().map(|Pos { x: _, y }| y).max().unwrap() + 1;
+
+    const SAND_SOURCE_POS: Pos = Pos { x: 500, y: 0 };
+
+    let mut rested_sand = 0;
+
+    'outer: loop {
+        let mut sand_pos = SAND_SOURCE_POS;
+        loop {
+            if sand_pos.y != floor_y {
+                if !walls.contains(&sand_pos.down()) {
+                    sand_pos = sand_pos.down();
+                    continue;
+                }
+                if !walls.contains(&sand_pos.down_left()) {
+                    sand_pos = sand_pos.down_left();
+                    continue;
+                }
+                if !walls.contains(&sand_pos.down_right()) {
+                    sand_pos = sand_pos.down_right();
+                    continue;
+                }
+            }
+
+            rested_sand += 1;
+
+            if sand_pos == SAND_SOURCE_POS {
+                break 'outer;
+            }
+
+            walls.insert(sand_pos);
+            break;
+        }
+    }
+
+    Some(rested_sand)
+}
+
+pub fn variants() -> Vec<Variant> {
+    vec![
+        Variant::new(1, "part_one", part_one),
+        Variant::new(2, "part_two", part_two),
+    ]
+}
@@ -0,0 +1,654 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Seeded generators of valid puzzle inputs, to stress the solutions.
+//!
+//! The `size` of an input is the number of its main elements (elves, lines, moves,
+//! valves...), as documented on each generator.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng as _, SeedableRng};
+
+use crate::day15::MAX_COORDINATE;
+
+/// Random number generator of the generators, stable across platforms and versions.
+pub type Rng = rand_chacha::ChaCha8Rng;
+
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// Generator of the inputs of `day`, if there is one.
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => calories,
+        2 => strategy_guide,
+        3 => rucksacks,
+        4 => section_assignments,
+        5 => crate_stacks,
+        6 => datastream,
+        7 => terminal_log,
+        8 => tree_map,
+        9 => rope_moves,
+        10 => cpu_program,
+        11 => monkeys,
+        12 => heightmap,
+        13 => packets,
+        14 => rock_paths,
+        15 => sensors,
+        16 => valves,
+        17 => jet_pattern,
+        18 => cubes,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// Generates an input of `day` from `seed`.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let mut rng = Rng::seed_from_u64(seed);
+    generator(day).map(|generator| generator(&mut rng, size))
+}
+
+fn letters(rng: &mut Rng, len: usize) -> String {
+    (0..len)
+        .map(|_| rng.random_range(b'a'..=b'z') as char)
+        .collect()
+}
+
+/// Day 1: `size` elves, carrying 1 to 10 items each.
+pub fn calories(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(3))
+        .map(|_| {
+            let items = rng.random_range(1..=10);
+            (0..items)
+                .map(|_| format!("{}\n", rng.random_range(1000..=70000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Day 2: `size` rounds.
+pub fn strategy_guide(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let opponent = rng.random_range(b'A'..=b'C') as char;
+            let response = rng.random_range(b'X'..=b'Z') as char;
+            format!("{opponent} {response}\n")
+        })
+        .collect()
+}
+
+/// Day 3: `size` groups of three rucksacks.
+///
+/// The compartments of a rucksack share exactly one item type, and the rucksacks of a
+/// group share exactly one badge.
+pub fn rucksacks(rng: &mut Rng, size: usize) -> String {
+    const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    // Longest compartment besides the shared items, so that the first two rucksacks of
+    // a group always leave items to the last one.
+    const MAX_LEN: usize = 20;
+
+    let mut output = String::new();
+    for _ in 0..size.max(1) {
+        let badge = *ITEMS.choose(rng).unwrap();
+        let mut group: Vec<BTreeSet<u8>> = Vec::new();
+        for i in 0..3 {
+            // The last rucksack must not contain the other items shared by the first two.
+            let forbidden: BTreeSet<u8> = match i {
+                2 => group[0].intersection(&group[1]).copied().collect(),
+                _ => BTreeSet::new(),
+            };
+            let mut pool: Vec<u8> = ITEMS
+                .iter()
+                .copied()
+                .filter(|item| *item != badge && !forbidden.contains(item))
+                .collect();
+            pool.shuffle(rng);
+
+            let shared = pool.pop().unwrap();
+            let len = rng.random_range(1..=MAX_LEN.min((pool.len() - 1) / 2));
+            let (left, right) = pool.split_at(len);
+            let mut left = left.to_vec();
+            let mut right = right[..len + 1].to_vec();
+            left.extend([shared, badge]);
+            right.push(shared);
+            left.shuffle(rng);
+            right.shuffle(rng);
+
+            group.push(left.iter().chain(&right).copied().collect());
+            output.push_str(std::str::from_utf8(&left).unwrap());
+            output.push_str(std::str::from_utf8(&right).unwrap());
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// Day 4: `size` pairs of section assignments.
+pub fn section_assignments(rng: &mut Rng, size: usize) -> String {
+    let range = |rng: &mut Rng| {
+        let start = rng.random_range(1..=99);
+        let end = rng.random_range(start..=99);
+        format!("{start}-{end}")
+    };
+    (0..size.max(1))
+        .map(|_| format!("{},{}\n", range(rng), range(rng)))
+        .collect()
+}
+
+/// Day 5: a drawing of up to 9 stacks, followed by `size` valid moves.
+///
+/// No stack is ever emptied, so that every stack has a top crate at the end.
+pub fn crate_stacks(rng: &mut Rng, size: usize) -> String {
+    let count = rng.random_range(2..=9);
+    let mut stacks: Vec<Vec<u8>> = (0..count)
+        .map(|_| {
+            let height = rng.random_range(1..=8);
+            (0..height).map(|_| rng.random_range(b'A'..=b'Z')).collect()
+        })
+        .collect();
+
+    let mut output = String::new();
+    let max_height = stacks.iter().map(Vec::len).max().unwrap();
+    for level in (0..max_height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(&c) => format!("[{}]", c as char),
+                None => "   ".to_string(),
+            })
+            .collect();
+        output.push_str(&row.join(" "));
+        output.push('\n');
+    }
+    let indices: Vec<String> = (1..=count).map(|i| format!(" {i} ")).collect();
+    output.push_str(&indices.join(" "));
+    output.push_str("\n\n");
+
+    for _ in 0..size {
+        let candidates: Vec<usize> = (0..count).filter(|&i| stacks[i].len() >= 2).collect();
+        let Some(&from) = candidates.choose(rng) else {
+            break;
+        };
+        let to = (from + rng.random_range(1..count)) % count;
+        let amount = rng.random_range(1..stacks[from].len());
+        let remaining = stacks[from].len() - amount;
+        let moved = stacks[from].split_off(remaining);
+        stacks[to].extend(moved);
+        writeln!(output, "move {amount} from {} to {}", from + 1, to + 1).unwrap();
+    }
+    output
+}
+
+/// Day 6: a datastream of `size` characters, ending with a marker.
+pub fn datastream(rng: &mut Rng, size: usize) -> String {
+    let mut output = letters(rng, size.saturating_sub(14));
+    let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+    marker.shuffle(rng);
+    output.push_str(std::str::from_utf8(&marker[..14]).unwrap());
+    output.push('\n');
+    output
+}
+
+/// Day 7: the terminal log of the exploration of a tree of about `size` entries.
+///
+/// The disk is filled with 40M to 70M, so that part two has space to free.
+pub fn terminal_log(rng: &mut Rng, size: usize) -> String {
+    const USED_SPACE: std::ops::Range<u32> = 40_000_000..70_000_000;
+
+    /// Writes the log of a directory, and returns its size.
+    fn explore(rng: &mut Rng, output: &mut String, budget: &mut usize, depth: usize) -> u32 {
+        output.push_str("$ ls\n");
+        // Keeps the whole tree below the used space.
+        let max_file_size = (30_000_000 / (*budget as u32).max(1)).clamp(1, 100_000);
+        let mut names = HashSet::new();
+        let mut dirs = Vec::new();
+        let mut size = 0;
+        let entries = rng.random_range(1..=8).min((*budget).max(1));
+        for _ in 0..entries {
+            *budget = budget.saturating_sub(1);
+            let name = loop {
+                let len = rng.random_range(1..=8);
+                let name = letters(rng, len);
+                if names.insert(name.clone()) {
+                    break name;
+                }
+            };
+            if depth < 8 && rng.random_bool(0.3) {
+                writeln!(output, "dir {name}").unwrap();
+                dirs.push(name);
+            } else {
+                let extension = ["", ".txt", ".dat", ".log"].choose(rng).unwrap();
+                let file_size = rng.random_range(1..=max_file_size);
+                writeln!(output, "{file_size} {name}{extension}").unwrap();
+                size += file_size;
+            }
+        }
+        for dir in dirs {
+            writeln!(output, "$ cd {dir}").unwrap();
+            size += explore(rng, output, budget, depth + 1);
+            output.push_str("$ cd ..\n");
+        }
+        size
+    }
+
+    let mut tree = String::new();
+    let mut budget = size.max(1);
+    let used = explore(rng, &mut tree, &mut budget, 0);
+
+    // Fill the disk with big files at the root.
+    let (ls, entries) = tree.split_once('\n').unwrap();
+    let mut output = format!("$ cd /\n{ls}\n");
+    let mut padding = rng.random_range(USED_SPACE) - used;
+    for i in 0.. {
+        let file_size = padding.min(rng.random_range(1_000_000..=20_000_000));
+        writeln!(output, "{file_size} padding{i}.bin").unwrap();
+        padding -= file_size;
+        if padding == 0 {
+            break;
+        }
+    }
+    output.push_str(entries);
+    output
+}
+
+/// Day 8: a square map of `size` × `size` trees, at least 3 × 3.
+pub fn tree_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    (0..size)
+        .map(|_| {
+            let mut line: String = (0..size)
+                .map(|_| rng.random_range(b'0'..=b'9') as char)
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+/// Day 9: `size` moves of the head of the rope.
+pub fn rope_moves(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let direction = *["U", "D", "L", "R"].choose(rng).unwrap();
+            format!("{direction} {}\n", rng.random_range(1..=20))
+        })
+        .collect()
+}
+
+/// Day 10: a program lasting the 240 cycles drawn on the screen.
+///
+/// The length of a program is fixed by the screen, so `size` is ignored.
+pub fn cpu_program(rng: &mut Rng, _size: usize) -> String {
+    let mut output = String::new();
+    let mut cycles = 0;
+    while cycles < 240 {
+        if cycles == 239 || rng.random_bool(0.3) {
+            output.push_str("noop\n");
+            cycles += 1;
+        } else {
+            writeln!(output, "addx {}", rng.random_range(-10..=10)).unwrap();
+            cycles += 2;
+        }
+    }
+    output
+}
+
+/// Day 11: 2 to 8 monkeys holding `size` items in total.
+///
+/// Inputs whose worry levels would overflow during part one are discarded.
+pub fn monkeys(rng: &mut Rng, size: usize) -> String {
+    const PRIMES: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+    enum Operation {
+        Add(u64),
+        Mul(u64),
+        Square,
+    }
+
+    loop {
+        let count = rng.random_range(2..=8);
+        let mut tests = PRIMES;
+        tests.shuffle(rng);
+        let square = rng.random_range(0..count);
+        let operations: Vec<Operation> = (0..count)
+            .map(|i| match i {
+                _ if i == square => Operation::Square,
+                _ if rng.random_bool(0.5) => Operation::Add(rng.random_range(1..=8)),
+                _ => Operation::Mul(rng.random_range(2..=19)),
+            })
+            .collect();
+        let targets: Vec<(usize, usize)> = (0..count)
+            .map(|i| {
+                let mut others: Vec<usize> = (0..count).filter(|&j| j != i).collect();
+                others.shuffle(rng);
+                (others[0], *others.last().unwrap())
+            })
+            .collect();
+        let mut items: Vec<Vec<u64>> = vec![Vec::new(); count];
+        for _ in 0..size.max(count) {
+            items[rng.random_range(0..count)].push(rng.random_range(40..=99));
+        }
+        if items.iter().any(Vec::is_empty) {
+            continue;
+        }
+
+        // Simulate part one with checked arithmetic.
+        let mut simulation = items.clone();
+        let overflows = (0..20).any(|_| {
+            (0..count).any(|i| {
+                let held = std::mem::take(&mut simulation[i]);
+                held.into_iter().any(|item| {
+                    let item = match operations[i] {
+                        Operation::Add(x) => item.checked_add(x),
+                        Operation::Mul(x) => item.checked_mul(x),
+                        Operation::Square => item.checked_mul(item),
+                    };
+                    let Some(item) = item.map(|item| item / 3) else {
+                        return true;
+                    };
+                    let (if_true, if_false) = targets[i];
+                    let recipient = if item % tests[i] == 0 {
+                        if_true
+                    } else {
+                        if_false
+                    };
+                    simulation[recipient].push(item);
+                    false
+                })
+            })
+        });
+        if overflows {
+            continue;
+        }
+
+        let mut output = Vec::new();
+        for i in 0..count {
+            let items: Vec<String> = items[i].iter().map(u64::to_string).collect();
+            let operation = match operations[i] {
+                Operation::Add(x) => format!("+ {x}"),
+                Operation::Mul(x) => format!("* {x}"),
+                Operation::Square => "* old".to_string(),
+            };
+            output.push(format!(
+                "Monkey {i}:\n  Starting items: {}\n  Operation: new = old {operation}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                items.join(", "),
+                tests[i],
+                targets[i].0,
+                targets[i].1
+            ));
+        }
+        return output.join("\n");
+    }
+}
+
+/// Day 12: a heightmap `size` squares wide, with a climbable path from `S` to `E`.
+pub fn heightmap(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(8);
+    let height = (size / 2).max(4);
+    let mut map: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| rng.random_range(b'a'..=b'z')).collect())
+        .collect();
+
+    // Carve a path climbing one step at most, snaking through the rows.
+    let cells = width * height;
+    let len = rng.random_range(26..=cells);
+    for i in 0..len {
+        let (y, x) = (i / width, i % width);
+        let x = if y % 2 == 0 { x } else { width - 1 - x };
+        map[y][x] = match i {
+            0 => b'S',
+            _ if i == len - 1 => b'E',
+            _ => b'a' + (25 * i / (len - 1)) as u8,
+        };
+    }
+
+    map.into_iter()
+        .map(|mut line| {
+            line.push(b'\n');
+            String::from_utf8(line).unwrap()
+        })
+        .collect()
+}
+
+/// Day 13: `size` pairs of distinct packets.
+pub fn packets(rng: &mut Rng, size: usize) -> String {
+    fn packet(rng: &mut Rng, depth: usize) -> String {
+        let len = rng.random_range(0..=5);
+        let items: Vec<String> = (0..len)
+            .map(|_| {
+                if depth < 4 && rng.random_bool(0.3) {
+                    packet(rng, depth + 1)
+                } else {
+                    rng.random_range(0..=10).to_string()
+                }
+            })
+            .collect();
+        format!("[{}]", items.join(","))
+    }
+
+    (0..size.max(1))
+        .map(|_| loop {
+            let (left, right) = (packet(rng, 0), packet(rng, 0));
+            if left != right {
+                break format!("{left}\n{right}\n");
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Day 14: `size` paths of rock, below the source of the sand.
+pub fn rock_paths(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let mut x: usize = rng.random_range(460..=540);
+            let mut y: usize = rng.random_range(13..=170);
+            let mut points = vec![format!("{x},{y}")];
+            for i in 0..rng.random_range(1..=5) {
+                let length = rng.random_range(1..=10);
+                if i % 2 == 0 {
+                    x = if rng.random_bool(0.5) {
+                        x + length
+                    } else {
+                        x - length
+                    };
+                } else {
+                    y = if rng.random_bool(0.5) {
+                        y + length
+                    } else {
+                        y.saturating_sub(length).max(1)
+                    };
+                }
+                points.push(format!("{x},{y}"));
+            }
+            format!("{}\n", points.join(" -> "))
+        })
+        .collect()
+}
+
+/// Day 15: `size` sensors around the search area of part two.
+///
+/// Each beacon is the unique closest one of its sensors. The position of the
+/// distress beacon is not guaranteed to be unique.
+pub fn sensors(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let max = MAX_COORDINATE;
+    let beacons: Vec<(i32, i32)> = (0..size / 2 + 1)
+        .map(|_| {
+            let x = rng.random_range(-max / 4..=max + max / 4);
+            let y = rng.random_range(-max / 4..=max + max / 4);
+            (x, y)
+        })
+        .collect();
+    let distance = |(ax, ay): (i32, i32), (bx, by): (i32, i32)| ax.abs_diff(bx) + ay.abs_diff(by);
+
+    let mut output = String::new();
+    let mut sensors = HashSet::new();
+    while sensors.len() < size {
+        let sensor = (rng.random_range(0..=max), rng.random_range(0..=max));
+        let mut by_distance: Vec<_> = beacons
+            .iter()
+            .map(|&beacon| (distance(sensor, beacon), beacon))
+            .collect();
+        by_distance.sort_unstable();
+        let (nearest, beacon) = by_distance[0];
+        let unique = by_distance.get(1).is_none_or(|&(d, _)| d > nearest);
+        if nearest > 0 && unique && sensors.insert(sensor) {
+            writeln!(
+                output,
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.0, sensor.1, beacon.0, beacon.1
+            )
+            .unwrap();
+        }
+    }
+    output
+}
+
+/// Day 16: a connected network of `size` valves, starting from `AA`.
+///
+/// At most 15 valves have a flow rate, as part one is exponential in their number.
+pub fn valves(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(2, 26 * 26);
+    let mut names: Vec<String> = (b'A'..=b'Z')
+        .flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", a as char, b as char)))
+        .filter(|name| name != "AA")
+        .collect();
+    names.shuffle(rng);
+    names.truncate(size - 1);
+    names.insert(0, "AA".to_string());
+
+    // Long corridors branching off each other, plus a few shortcuts, like real networks.
+    let mut tunnels: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); size];
+    for i in 1..size {
+        let j = rng.random_range(i.saturating_sub(3)..i);
+        tunnels[i].insert(j);
+        tunnels[j].insert(i);
+    }
+    for _ in 0..size / 10 {
+        let (i, j) = (rng.random_range(0..size), rng.random_range(0..size));
+        if i != j {
+            tunnels[i].insert(j);
+            tunnels[j].insert(i);
+        }
+    }
+
+    let mut flow_rates = vec![0; size];
+    let mut with_flow: Vec<usize> = (1..size).collect();
+    with_flow.shuffle(rng);
+    for &i in with_flow.iter().take((size / 4).clamp(1, 15)) {
+        flow_rates[i] = rng.random_range(1..=25);
+    }
+
+    let mut order: Vec<usize> = (0..size).collect();
+    order.shuffle(rng);
+    let index: HashMap<usize, &str> = (0..size).map(|i| (i, names[i].as_str())).collect();
+    order
+        .into_iter()
+        .map(|i| {
+            let neighbors: Vec<&str> = tunnels[i].iter().map(|j| index[j]).collect();
+            let tunnels = match neighbors.len() {
+                1 => "tunnel leads to valve",
+                _ => "tunnels lead to valves",
+            };
+            format!(
+                "Valve {} has flow rate={}; {tunnels} {}\n",
+                names[i],
+                flow_rates[i],
+                neighbors.join(", ")
+            )
+        })
+        .collect()
+}
+
+/// Day 17: a jet pattern of `size` pushes.
+pub fn jet_pattern(rng: &mut Rng, size: usize) -> String {
+    let mut output: String = (0..size.max(1))
+        .map(|_| if rng.random_bool(0.5) { '<' } else { '>' })
+        .collect();
+    output.push('\n');
+    output
+}
+
+/// Day 18: `size` distinct cubes, packed in a box to create air pockets.
+pub fn cubes(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let side = ((size as f64 * 1.25).cbrt().ceil() as u32).max(2);
+    let mut cubes = HashSet::new();
+    let mut output = String::new();
+    while cubes.len() < size {
+        let cube = (
+            rng.random_range(0..side),
+            rng.random_range(0..side),
+            rng.random_range(0..side),
+        );
+        if cubes.insert(cube) {
+            writeln!(output, "{},{},{}", cube.0, cube.1, cube.2).unwrap();
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variant::run_all;
+    use itertools::Itertools;
+
+    #[test]
+    #[cfg_attr(miri, ignore = "too slow")]
+    fn test_generate_is_seeded() {
+        for day in 1..=25 {
+            assert_eq!(generate(day, 1, 10), generate(day, 1, 10));
+        }
+        assert_ne!(generate(1, 1, 10), generate(1, 2, 10));
+        assert_eq!(generate(26, 1, 10), None);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "too slow")]
+    fn test_generators_snapshots() {
+        for day in 1..=25 {
+            if let Some(input) = generate(day, 0, 5) {
+                insta::assert_snapshot!(format!("day{day:02}"), input);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "too slow")]
+    fn test_rucksacks_at_large_sizes() {
+        for seed in 0..8 {
+            let input = generate(3, seed, 3000).unwrap();
+            assert_eq!(input.lines().count(), 9000);
+            for run in run_all(&crate::variants(3).unwrap(), &input) {
+                assert_eq!(run.disagreements().count(), 0, "seed {seed}");
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "too slow")]
+    fn test_generated_inputs_are_solved() {
+        for day in 1..=25 {
+            let Some(variants) = crate::variants(day) else {
+                continue;
+            };
+            for (seed, size) in (0..3).cartesian_product([0, 1, 2, 10]) {
+                let input = generate(day, seed, size).unwrap();
+                for run in run_all(&variants, &input) {
+                    assert_eq!(
+                        run.disagreements().count(),
+                        0,
+                        "day {day} seed {seed} size {size}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,32 @@
 mod grid;
 pub mod parser;
 
-use std::{iter::Step, ops::Add};
+#[cfg(feature = "nightly")]
+use std::iter::Step;
+use std::ops::Add;
+#[cfg(not(feature = "nightly"))]
+use std::ops::RangeInclusive;
 
 pub use grid::*;
 
+/// Evaluates `$unchecked` in an `unsafe` block, or `$checked` with the `safe` feature
+/// which forbids `unsafe` code.
+#[cfg(not(feature = "safe"))]
+macro_rules! unchecked {
+    ($unchecked:expr, $checked:expr) => {
+        unsafe { $unchecked }
+    };
+}
+
+#[cfg(feature = "safe")]
+macro_rules! unchecked {
+    ($unchecked:expr, $checked:expr) => {
+        $checked
+    };
+}
+
+pub(crate) use unchecked;
+
 pub trait RemoveValue<T> {
     /// Removes and returns the first item that is equal to value if any.
     fn remove_value(&mut self, value: &T) -> Option<T>;
@@ -18,6 +40,7 @@ impl<T: PartialEq> RemoveValue<T> for Vec<T> {
     }
 }
 
+#[cfg(not(feature = "safe"))]
 pub trait StrExt {
     /// Divide one string slice into two at an index, without doing bounds checking.
     ///
@@ -40,7 +63,8 @@ pub trait StrExt {
     unsafe fn split_at_unchecked(&self, mid: usize) -> (&str, &str);
 }
 
-impl<'a> StrExt for &'a str {
+#[cfg(not(feature = "safe"))]
+impl StrExt for &str {
     #[inline]
     unsafe fn split_at_unchecked(&self, mid: usize) -> (&str, &str) {
         // SAFETY: the caller must uphold the safety contract for `get_unchecked`;
@@ -69,6 +93,20 @@ impl<'a> StrExt for &'a str {
 /// assert_eq!(values.next(), Some(1));
 /// assert_eq!(values.next(), None);
 /// ```
+#[cfg(not(feature = "nightly"))]
+pub fn range<'a, T: 'a + PartialOrd>(a: T, b: T) -> Box<dyn Iterator<Item = T> + 'a>
+where
+    RangeInclusive<T>: DoubleEndedIterator<Item = T>,
+{
+    if a < b {
+        Box::new(a..=b)
+    } else {
+        Box::new((b..=a).rev())
+    }
+}
+
+/// Iterator from `a` (included) to `b` (included), for any [`Step`] type.
+#[cfg(feature = "nightly")]
 pub fn range<'a, T: 'a + PartialOrd + Step>(a: T, b: T) -> Box<dyn Iterator<Item = T> + 'a> {
     if a < b {
         Box::new(a..=b)
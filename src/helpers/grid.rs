@@ -1,5 +1,7 @@
 use std::iter::Enumerate;
 
+use super::unchecked;
+
 #[derive(Debug, Default, Clone)]
 pub struct Grid<T> {
     elements: Box<[T]>,
@@ -56,7 +58,10 @@ impl<T> Grid<T> {
         let GridIndex { x, y } = index.into();
         if x < self.width() && y < self.height() {
             // Safety: `x` and `y` are in bounds.
-            Some(unsafe { self.get_unchecked(x, y) })
+            Some(unchecked!(
+                self.get_unchecked(x, y),
+                &self.elements[flat_index(x, y, self.width)]
+            ))
         } else {
             None
         }
@@ -65,6 +70,7 @@ impl<T> Grid<T> {
     ///
     /// # Safety
     /// `x` and `y` must be in bounds of the grid.
+    #[cfg(not(feature = "safe"))]
     pub unsafe fn get_unchecked(&self, x: usize, y: usize) -> &T {
         self.elements.get_unchecked(flat_index(x, y, self.width))
     }
@@ -73,7 +79,10 @@ impl<T> Grid<T> {
         let GridIndex { x, y } = index.into();
         if x < self.width() && y < self.height() {
             // Safety: `x` and `y` are in bounds.
-            Some(unsafe { self.get_unchecked_mut(x, y) })
+            Some(unchecked!(
+                self.get_unchecked_mut(x, y),
+                &mut self.elements[flat_index(x, y, self.width)]
+            ))
         } else {
             None
         }
@@ -82,19 +91,20 @@ impl<T> Grid<T> {
     ///
     /// # Safety
     /// `x` and `y` must be in bounds of the grid.
+    #[cfg(not(feature = "safe"))]
     pub unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut T {
         self.elements
             .get_unchecked_mut(flat_index(x, y, self.width))
     }
 
-    pub fn iter_with_index(&self) -> IterWithIndex<T> {
+    pub fn iter_with_index(&self) -> IterWithIndex<'_, T> {
         IterWithIndex {
             inner: self.elements.iter().enumerate(),
             width: self.width,
         }
     }
 
-    pub fn iter_with_index_mut(&mut self) -> IterWithIndexMut<T> {
+    pub fn iter_with_index_mut(&mut self) -> IterWithIndexMut<'_, T> {
         IterWithIndexMut {
             inner: self.elements.iter_mut().enumerate(),
             width: self.width,
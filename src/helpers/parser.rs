@@ -7,6 +7,9 @@ use nom::{
 };
 use num::Num;
 
+/// Error of a parser, pointing at the input it failed on.
+pub type ParseError<'a> = nom::error::Error<&'a str>;
+
 pub fn decimal(input: &str) -> IResult<&str, &str> {
     recognize(preceded(
         many_m_n(0, 1, char('-')),
@@ -0,0 +1,215 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Statistics of a puzzle input, to check assumptions before writing its parser.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use itertools::Itertools;
+
+/// Integers found in the input, `-` being a sign unless it follows a letter or a digit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Numbers {
+    pub count: usize,
+    pub min: i128,
+    pub max: i128,
+    pub negatives: usize,
+    pub max_digits: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub lines: usize,
+    /// Number of lines of each length.
+    pub line_lengths: BTreeMap<usize, usize>,
+    pub charset: BTreeSet<char>,
+    pub numbers: Option<Numbers>,
+    /// Number of lines of each section separated by blank lines.
+    pub sections: Vec<usize>,
+    /// Width and height of the input if it is one section of lines of the same length.
+    pub grid: Option<(usize, usize)>,
+}
+
+pub fn inspect(input: &str) -> Stats {
+    let lines = input.lines().collect_vec();
+
+    let mut sections = vec![0];
+    for line in &lines {
+        match (line.is_empty(), sections.last_mut()) {
+            (true, Some(0)) => {}
+            (true, _) => sections.push(0),
+            (false, Some(count)) => *count += 1,
+            (false, None) => unreachable!(),
+        }
+    }
+    if sections.last() == Some(&0) {
+        sections.pop();
+    }
+
+    // lists of values, like the cubes of day 18, are no grids even with lines of equal length.
+    let is_list = lines.iter().any(|line| line.contains([' ', ',']));
+    let widths = lines.iter().map(|line| line.chars().count()).collect_vec();
+    let grid = match (sections.as_slice(), widths.first()) {
+        ([height], Some(&width))
+            if *height > 1 && width > 1 && !is_list && widths.iter().all_equal() =>
+        {
+            Some((width, *height))
+        }
+        _ => None,
+    };
+
+    Stats {
+        lines: lines.len(),
+        line_lengths: widths.into_iter().counts().into_iter().collect(),
+        charset: input.chars().filter(|c| *c != '\n').collect(),
+        numbers: numbers(input),
+        sections,
+        grid,
+    }
+}
+
+fn numbers(input: &str) -> Option<Numbers> {
+    let bytes = input.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let digits = &input[start..i];
+        let negative = start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric());
+        // numbers too large for an i128 are clamped, their digits still tell the width.
+        let value = digits.parse::<i128>().unwrap_or(i128::MAX);
+        numbers.push((if negative { -value } else { value }, digits.len()));
+    }
+
+    let (min, max) = numbers.iter().map(|(n, _)| *n).minmax().into_option()?;
+    Some(Numbers {
+        count: numbers.len(),
+        min,
+        max,
+        negatives: numbers.iter().filter(|(n, _)| *n < 0).count(),
+        max_digits: numbers.iter().map(|(_, digits)| *digits).max()?,
+    })
+}
+
+fn format_char(c: char) -> String {
+    match c {
+        ' ' => "␠".to_string(),
+        '\t' => "\\t".to_string(),
+        '\r' => "\\r".to_string(),
+        c => c.to_string(),
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "lines: {}", self.lines)?;
+
+        let (min, max) = match self.line_lengths.keys().minmax().into_option() {
+            Some((min, max)) => (*min, *max),
+            None => (0, 0),
+        };
+        writeln!(f, "line lengths: {min}..={max}")?;
+        // list the lengths when there are few of them, otherwise the total is enough.
+        if self.line_lengths.len() <= 10 {
+            for (length, count) in &self.line_lengths {
+                writeln!(f, "  {length:>5}: {count} lines")?;
+            }
+        } else {
+            writeln!(f, "  {} distinct lengths", self.line_lengths.len())?;
+        }
+
+        writeln!(
+            f,
+            "charset ({}): {}",
+            self.charset.len(),
+            self.charset.iter().map(|c| format_char(*c)).join("")
+        )?;
+
+        match &self.numbers {
+            Some(numbers) => writeln!(
+                f,
+                "numbers: {} in {}..={}, {} negative, up to {} digits",
+                numbers.count, numbers.min, numbers.max, numbers.negatives, numbers.max_digits
+            )?,
+            None => writeln!(f, "numbers: none")?,
+        }
+
+        let (min, max) = self
+            .sections
+            .iter()
+            .minmax()
+            .into_option()
+            .map_or((0, 0), |(min, max)| (*min, *max));
+        writeln!(
+            f,
+            "sections: {} of {min}..={max} lines",
+            self.sections.len()
+        )?;
+
+        match self.grid {
+            Some((width, height)) => write!(f, "grid: {width} × {height}"),
+            None => write!(f, "grid: no"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(day: u8) -> String {
+        crate::read_file("examples", day)
+    }
+
+    #[test]
+    fn test_grid() {
+        let stats = inspect(&example(8));
+        assert_eq!(stats.grid, Some((5, 5)));
+        assert_eq!(stats.line_lengths, BTreeMap::from([(5, 5)]));
+        assert_eq!(stats.charset, "012345679".chars().collect());
+        assert_eq!(stats.numbers.unwrap().max_digits, 5);
+    }
+
+    #[test]
+    fn test_sections() {
+        let stats = inspect(&example(1));
+        assert_eq!(stats.lines, 14);
+        assert_eq!(stats.sections, vec![3, 1, 2, 3, 1]);
+        assert_eq!(stats.grid, None);
+
+        let stats = inspect(&example(5));
+        assert_eq!(stats.sections, vec![4, 4]);
+        assert_eq!(stats.numbers.unwrap().max_digits, 1);
+    }
+
+    #[test]
+    fn test_numbers() {
+        let numbers = inspect(&example(15)).numbers.unwrap();
+        assert_eq!((numbers.min, numbers.max), (-2, 25));
+        assert_eq!(numbers.negatives, 1);
+        assert_eq!(numbers.count, 14 * 4);
+
+        // ranges are not negative numbers.
+        let numbers = inspect(&example(4)).numbers.unwrap();
+        assert_eq!((numbers.min, numbers.max, numbers.negatives), (2, 9, 0));
+
+        assert_eq!(inspect("abc\n").numbers, None);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "insta is unsupported")]
+    fn test_display() {
+        insta::assert_snapshot!(inspect(&example(18)).to_string());
+    }
+}
@@ -0,0 +1,236 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Rendering of private leaderboards, from the JSON served by adventofcode.com.
+
+use std::collections::HashMap;
+use std::time::{Duration, UNIX_EPOCH};
+
+use serde::Deserialize;
+
+use crate::aoc::{format_duration, unlock_time};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    #[serde(default)]
+    pub last_star_ts: u64,
+    pub completion_day_level: HashMap<u8, HashMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Timestamp at which `part` of `day` was solved.
+    pub fn star_ts(&self, day: u8, part: u8) -> Option<u64> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        Some(star.get_star_ts)
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Leaderboard, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn year(&self) -> Option<i32> {
+        self.event.parse().ok()
+    }
+
+    /// Members sorted by local score, the same way adventofcode.com does.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                std::cmp::Reverse(member.stars),
+                member.last_star_ts,
+                member.id,
+            )
+        });
+        members
+    }
+
+    /// Days for which at least one star was collected.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<_> = self
+            .members
+            .values()
+            .flat_map(|member| member.completion_day_level.keys().copied())
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+}
+
+pub fn render_ranking(leaderboard: &Leaderboard) -> String {
+    let mut output = format!(
+        "🎄 {ANSI_BOLD}Private leaderboard {}{ANSI_RESET} 🎄\n",
+        leaderboard.event
+    );
+    for (member, rank) in leaderboard.ranking().into_iter().zip(1..) {
+        output.push_str(&format!(
+            "{rank:>3}) {:>5} {:>3} ⭐  {}\n",
+            member.local_score,
+            member.stars,
+            member.display_name()
+        ));
+    }
+    output
+}
+
+/// Renders the time taken by each member to solve the parts of `day`, from the
+/// unlock of the puzzle, and the delta between both parts.
+///
+/// Fails if the year of the event is unknown, as the unlock is too.
+pub fn render_day(leaderboard: &Leaderboard, day: u8) -> Result<String, String> {
+    let unlock = leaderboard
+        .year()
+        .and_then(|year| unlock_time(year, day))
+        .ok_or_else(|| format!("unknown year of the event \"{}\"", leaderboard.event))?
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let elapsed = |ts: u64| Duration::from_secs(ts.saturating_sub(unlock));
+
+    let mut members: Vec<_> = leaderboard
+        .members
+        .values()
+        .filter_map(|member| {
+            let part_one = member.star_ts(day, 1)?;
+            Some((member, part_one, member.star_ts(day, 2)))
+        })
+        .collect();
+    members.sort_by_key(|&(member, part_one, part_two)| {
+        (part_two.is_none(), part_two, part_one, member.id)
+    });
+
+    let names: Vec<_> = members
+        .iter()
+        .map(|(member, _, _)| member.display_name())
+        .collect();
+    let width = names
+        .iter()
+        .map(|name| name.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut output = format!("{ANSI_BOLD}Day {day}{ANSI_RESET}\n");
+    for (name, (_, part_one, part_two)) in names.iter().zip(members) {
+        let part_one_time = format_duration(elapsed(part_one));
+        output.push_str(&format!("  {name:<width$}  {part_one_time:>12}"));
+        match part_two {
+            Some(part_two) => {
+                let part_two_time = format_duration(elapsed(part_two));
+                let delta = format_duration(Duration::from_secs(part_two.saturating_sub(part_one)));
+                output.push_str(&format!(
+                    "  {part_two_time:>12}  {ANSI_ITALIC}(+{delta}){ANSI_RESET}\n"
+                ));
+            }
+            None => output.push_str(&format!("  {:>12}\n", "--:--:--")),
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/leaderboard.json");
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+        assert_eq!(leaderboard.year(), Some(2022));
+        assert_eq!(leaderboard.members.len(), 3);
+        assert_eq!(leaderboard.days(), vec![1, 2]);
+
+        let anonymous = &leaderboard.members["1002"];
+        assert_eq!(anonymous.display_name(), "(anonymous user #1002)");
+        assert_eq!(anonymous.star_ts(1, 2), Some(1669878000));
+        assert_eq!(anonymous.star_ts(2, 2), None);
+    }
+
+    #[test]
+    fn test_render_ranking() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+        assert_eq!(
+            render_ranking(&leaderboard),
+            format!(
+                "🎄 {ANSI_BOLD}Private leaderboard 2022{ANSI_RESET} 🎄
+  1)    11   4 ⭐  Alice
+  2)     7   3 ⭐  (anonymous user #1002)
+  3)     0   0 ⭐  Carol
+"
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_day() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+        assert_eq!(
+            render_day(&leaderboard, 1).unwrap(),
+            format!(
+                "{ANSI_BOLD}Day 1{ANSI_RESET}
+  Alice                       00:05:00      00:10:00  {ANSI_ITALIC}(+00:05:00){ANSI_RESET}
+  (anonymous user #1002)      00:03:00      02:00:00  {ANSI_ITALIC}(+01:57:00){ANSI_RESET}
+"
+            )
+        );
+        assert_eq!(
+            render_day(&leaderboard, 2).unwrap(),
+            format!(
+                "{ANSI_BOLD}Day 2{ANSI_RESET}
+  Alice                       00:03:05      00:13:05  {ANSI_ITALIC}(+00:10:00){ANSI_RESET}
+  (anonymous user #1002)      01:00:00      --:--:--
+"
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_day_with_parts_out_of_order() {
+        let leaderboard = Leaderboard::parse(
+            &FIXTURE.replace("\"get_star_ts\": 1669871400", "\"get_star_ts\": 1669871000"),
+        )
+        .unwrap();
+        assert!(render_day(&leaderboard, 1).unwrap().contains("(+00:00:00)"));
+    }
+
+    #[test]
+    fn test_render_day_with_unknown_year() {
+        let leaderboard =
+            Leaderboard::parse(&FIXTURE.replace("\"event\": \"2022\"", "\"event\": \"x\""))
+                .unwrap();
+        assert_eq!(leaderboard.year(), None);
+        assert_eq!(
+            render_day(&leaderboard, 1),
+            Err("unknown year of the event \"x\"".to_string())
+        );
+        assert!(render_ranking(&leaderboard).contains("Private leaderboard x"));
+    }
+}
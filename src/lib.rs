@@ -1,4 +1,5 @@
-#![feature(step_trait)]
+#![cfg_attr(feature = "nightly", feature(step_trait))]
+#![cfg_attr(feature = "safe", forbid(unsafe_code))]
 
 /*
  * This file contains template code.
@@ -7,8 +8,24 @@
  */
 use std::env;
 use std::fs;
-
+use std::path::{Path, PathBuf};
+
+pub mod aoc;
+pub mod batch;
+pub mod config;
+pub mod crypt;
+mod days;
+pub mod generate;
 pub mod helpers;
+pub mod inspect;
+pub mod leaderboard;
+pub mod minimize;
+pub mod puzzle;
+pub mod stars;
+pub mod submissions;
+pub mod variant;
+
+pub use days::*;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -43,40 +60,86 @@ macro_rules! solve {
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
+fn file_path(folder: &str, day: u8) -> PathBuf {
     let cwd = env::current_dir().unwrap();
+    cwd.join(config::config().dir(folder))
+        .join(format!("{day:02}.txt"))
+}
+
+fn is_empty_file(path: &Path) -> bool {
+    !fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+pub fn read_file(folder: &str, day: u8) -> String {
+    let filepath = file_path(folder, day);
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+    // fall back to the encrypted file, see `cargo crypt`.
+    let encrypted = crypt::encrypted_path(&filepath);
+    if is_empty_file(&filepath) && encrypted.exists() {
+        return crypt::read_encrypted(&encrypted)
+            .unwrap_or_else(|e| panic!("could not decrypt input file: {e}"));
+    }
 
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
+/// Reads the file of `day` like [`read_file`], or returns `None` if it is missing or empty.
+pub fn try_read_file(folder: &str, day: u8) -> Option<String> {
+    let filepath = file_path(folder, day);
+    if is_empty_file(&filepath) && is_empty_file(&crypt::encrypted_path(&filepath)) {
+        return None;
+    }
+    Some(read_file(folder, day))
+}
+
+/// Extracts the answer printed by [`solve!`] for `part` from the output of a solution.
+pub fn parse_answer(output: &str, part: u8) -> Option<String> {
+    let header = format!("🎄 {ANSI_BOLD}Part {part}{ANSI_RESET} 🎄\n");
+    let start = output.find(&header)? + header.len();
+    let rest = &output[start..];
+    let end = rest.find(&format!(" {ANSI_ITALIC}(elapsed: "))?;
+    Some(rest[..end].to_string())
+}
+
 fn parse_time(val: &str, postfix: &str) -> f64 {
     val.split(postfix).next().unwrap().parse().unwrap()
 }
 
+/// Milliseconds printed by [`solve!`] on `line`, if it has a timing.
+fn parse_elapsed(line: &str) -> Option<f64> {
+    if !line.contains("elapsed:") {
+        return None;
+    }
+    let timing = line.split("(elapsed: ").last().unwrap();
+    // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    if timing.contains("ns)") {
+        Some(0_f64) // range below rounding precision.
+    } else if timing.contains("µs)") {
+        Some(parse_time(timing, "µs") / 1000_f64)
+    } else if timing.contains("ms)") {
+        Some(parse_time(timing, "ms"))
+    } else if timing.contains("s)") {
+        Some(parse_time(timing, "s") * 1000_f64)
+    } else {
+        None
+    }
+}
+
 pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+    output.lines().filter_map(parse_elapsed).sum()
+}
+
+/// Milliseconds taken by `part` in the output of a solution, see [`parse_exec_time`].
+pub fn parse_part_time(output: &str, part: u8) -> Option<f64> {
+    let header = format!("Part {part}{ANSI_RESET} 🎄");
+    let start = output.find(&header)? + header.len();
+    output[start..]
+        .lines()
+        .skip(1)
+        .take_while(|line| !line.starts_with('🎄'))
+        .find_map(parse_elapsed)
 }
 
 /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -123,4 +186,26 @@ mod tests {
             10400.50_f64
         );
     }
+
+    #[test]
+    fn test_parse_part_time() {
+        let output = format!(
+            "🎄 {ANSI_BOLD}Part 1{ANSI_RESET} 🎄\nnot solved.\n🎄 {ANSI_BOLD}Part 2{ANSI_RESET} 🎄\nCMZ {ANSI_ITALIC}(elapsed: 74.13µs){ANSI_RESET}\n  part_two  74.13µs\n"
+        );
+        assert_eq!(parse_part_time(&output, 1), None);
+        assert_approx_eq!(parse_part_time(&output, 2).unwrap(), 0.07413_f64);
+        assert_eq!(parse_part_time(&output, 3), None);
+    }
+
+    #[test]
+    fn test_parse_answer() {
+        let output = format!(
+            "🎄 {ANSI_BOLD}Part 1{ANSI_RESET} 🎄\nCMZ {ANSI_ITALIC}(elapsed: 74.13µs){ANSI_RESET}\n🎄 {ANSI_BOLD}Part 2{ANSI_RESET} 🎄\n#.\n.# {ANSI_ITALIC}(elapsed: 1.30ms){ANSI_RESET}\n"
+        );
+        assert_eq!(parse_answer(&output, 1).as_deref(), Some("CMZ"));
+        assert_eq!(parse_answer(&output, 2).as_deref(), Some("#.\n.#"));
+
+        let output = format!("🎄 {ANSI_BOLD}Part 1{ANSI_RESET} 🎄\nnot solved.\n");
+        assert_eq!(parse_answer(&output, 1), None);
+    }
 }
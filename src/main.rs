@@ -2,16 +2,51 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use adventofcode::batch::{self, Outcome, Run};
+use adventofcode::config::config;
 use adventofcode::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use itertools::Itertools;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::{fs, panic};
+
+struct Args {
+    inputs_dir: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        inputs_dir: args.opt_value_from_str("--inputs-dir")?,
+    })
+}
 
 fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo all --inputs-dir inputs`");
+            process::exit(1);
+        }
+    };
+    match args.inputs_dir {
+        Some(dir) => run_inputs(&dir),
+        None => run_days(),
+    }
+}
+
+/// Runs the solution of each day on its input, failing if it exceeds its budgets.
+fn run_days() {
+    let budgets = &config().budgets;
+    let mut over_budget = Vec::new();
     let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+        .map(|n| {
+            let day = format!("{n:02}");
 
             let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
+                .arg("run")
+                .args(&config().runner.cargo_args)
+                .args(["--bin", &day])
                 .output()
                 .unwrap();
 
@@ -31,6 +66,18 @@ fn main() {
                 }
             );
 
+            for part in 1..=2 {
+                let budget = budgets.part(n, part);
+                let elapsed = adventofcode::parse_part_time(&output, part);
+                if let (Some(budget), Some(elapsed)) = (budget, elapsed) {
+                    if elapsed > budget {
+                        over_budget.push(format!(
+                            "Day {day} part {part} took {elapsed:.2}ms, over its budget of {budget}ms."
+                        ));
+                    }
+                }
+            }
+
             if is_empty {
                 0_f64
             } else {
@@ -40,4 +87,108 @@ fn main() {
         .sum();
 
     println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+
+    if let Some(target) = budgets.total.filter(|target| total > *target) {
+        over_budget.push(format!("The total is over its target of {target}ms."));
+    }
+    if !over_budget.is_empty() {
+        for message in over_budget {
+            eprintln!("⏰ {message}");
+        }
+        process::exit(1);
+    }
+}
+
+/// Runs the variants of each day on every input of `dir`, reporting the failures,
+/// the panics and the outlier timings.
+fn run_inputs(dir: &Path) {
+    let inputs = match batch::find_inputs(dir) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {e}", dir.display());
+            process::exit(1);
+        }
+    };
+    // panics are reported with the runs.
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut reports: Vec<(&batch::Input, Vec<Run>)> = Vec::new();
+    for input in &inputs {
+        let Some(variants) = adventofcode::variants(input.day) else {
+            continue;
+        };
+        let runs = match fs::read_to_string(&input.path) {
+            Ok(content) => batch::run_input(&variants, &content, &input.answers()),
+            Err(e) => {
+                eprintln!("Failed to read \"{}\": {e}", input.path.display());
+                continue;
+            }
+        };
+        reports.push((input, runs));
+    }
+
+    // outliers are found among the inputs of the same day, for each variant.
+    let mut slow = vec![Vec::new(); reports.len()];
+    for day in reports.iter().map(|(input, _)| input.day).dedup() {
+        let group: Vec<usize> = (0..reports.len())
+            .filter(|&i| reports[i].0.day == day)
+            .collect();
+        for v in 0..reports[group[0]].1.len() {
+            let timings: Vec<_> = group.iter().map(|&i| reports[i].1[v].elapsed).collect();
+            for (i, ratio) in batch::outliers(&timings) {
+                slow[group[i]].push((v, ratio));
+            }
+        }
+    }
+
+    let (mut failures, mut panics) = (0, 0);
+    for ((input, runs), slow) in reports.iter().zip(&slow) {
+        println!(
+            "{ANSI_BOLD}Day {:02}{ANSI_RESET} {}",
+            input.day,
+            input
+                .path
+                .strip_prefix(dir)
+                .unwrap_or(&input.path)
+                .display()
+        );
+        for (v, run) in runs.iter().enumerate() {
+            let mut line = match &run.outcome {
+                Outcome::Panicked(message) => {
+                    panics += 1;
+                    format!("  {} 💥 panicked: {message}", run.variant)
+                }
+                outcome => {
+                    let answer = match outcome {
+                        Outcome::Solved(answer) if answer.contains('\n') => "(drawn)",
+                        Outcome::Solved(answer) => answer,
+                        _ => "not solved.",
+                    };
+                    format!(
+                        "  {} {answer} {ANSI_ITALIC}(elapsed: {:.2?}){ANSI_RESET}",
+                        run.variant, run.elapsed
+                    )
+                }
+            };
+            if let (Outcome::Solved(_), Some(expected)) = (&run.outcome, &run.expected) {
+                if run.failed() {
+                    failures += 1;
+                    line.push_str(&format!(" ❌ expected {expected}"));
+                }
+            }
+            if let Some((_, ratio)) = slow.iter().find(|(slow, _)| *slow == v) {
+                line.push_str(&format!(" 🐢 x{ratio:.1} the median"));
+            }
+            println!("{line}");
+        }
+    }
+
+    let outliers: usize = slow.iter().map(Vec::len).sum();
+    println!(
+        "{ANSI_BOLD}{} inputs:{ANSI_RESET} {failures} wrong answers, {panics} panics, {outliers} outlier timings",
+        reports.len()
+    );
+    if failures + panics > 0 {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,203 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Delta debugging (ddmin) of the inputs on which a part disagrees with a reference.
+
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::{Command, Stdio};
+
+use crate::variant::{Params, Variant};
+
+/// Smallest subsequence of `items` found to still `fail`, which must hold for `items`.
+///
+/// Removing any single item of the result makes it pass, but smaller failing
+/// subsequences may exist.
+pub fn ddmin<T: Clone>(items: &[T], mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut items = items.to_vec();
+    let mut n = 2;
+    while items.len() >= 2 {
+        let chunks: Vec<&[T]> = items.chunks(items.len().div_ceil(n)).collect();
+
+        if let Some(chunk) = chunks.iter().find(|chunk| fails(chunk)) {
+            items = chunk.to_vec();
+            n = 2;
+            continue;
+        }
+        // with two chunks, the complements are the chunks themselves.
+        let complement = (0..chunks.len())
+            .filter(|_| chunks.len() > 2)
+            .map(|skipped| {
+                chunks
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != skipped)
+                    .flat_map(|(_, chunk)| chunk.iter().cloned())
+                    .collect::<Vec<_>>()
+            })
+            .find(|complement| fails(complement));
+        if let Some(complement) = complement {
+            items = complement;
+            n = (n - 1).max(2);
+            continue;
+        }
+
+        if n >= items.len() {
+            break;
+        }
+        n = (n * 2).min(items.len());
+    }
+    items
+}
+
+/// Unit removed from the inputs while minimizing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    Lines,
+    /// Blocks of lines separated by blank lines.
+    Sections,
+}
+
+impl Granularity {
+    fn separator(self) -> &'static str {
+        match self {
+            Granularity::Lines => "\n",
+            Granularity::Sections => "\n\n",
+        }
+    }
+
+    pub fn split(self, input: &str) -> Vec<&str> {
+        input
+            .trim_end_matches('\n')
+            .split(self.separator())
+            .collect()
+    }
+
+    pub fn join(self, items: &[&str]) -> String {
+        items.join(self.separator()) + "\n"
+    }
+}
+
+/// Where the expected answers come from.
+pub enum Reference<'a> {
+    Variant(&'a Variant),
+    /// A shell command reading the input on stdin and printing the answer.
+    Command(String),
+}
+
+impl Reference<'_> {
+    pub fn answer(&self, input: &str, params: &Params) -> Option<String> {
+        match self {
+            Reference::Variant(variant) => solve(variant, input, params),
+            Reference::Command(command) => run_command(command, input),
+        }
+    }
+}
+
+/// Answer of `variant`, or `None` if it is not solved or panics on `input`.
+pub fn solve(variant: &Variant, input: &str, params: &Params) -> Option<String> {
+    panic::catch_unwind(AssertUnwindSafe(|| variant.solve_with(input, params)))
+        .ok()
+        .flatten()
+        .map(|answer| answer.to_string())
+}
+
+/// Trimmed output of `sh -c command` given `input` on stdin, or `None` if it fails.
+pub fn run_command(command: &str, input: &str) -> Option<String> {
+    let mut child = Command::new("sh")
+        .args(["-c", command])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    // the command may exit without reading all of its input.
+    let _ = child.stdin.take()?.write_all(input.as_bytes());
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8(output.stdout).ok()?.trim().to_string())
+}
+
+/// Smallest input found on which `variant` disagrees with `reference`, along with the
+/// answer of the reference, or `None` if they agree on `input`.
+///
+/// Inputs on which either side panics or has no answer are seen as invalid, and not kept.
+pub fn minimize(
+    variant: &Variant,
+    reference: &Reference,
+    input: &str,
+    params: &Params,
+    granularity: Granularity,
+) -> Option<(String, String)> {
+    let disagreement = |input: &str| {
+        let expected = reference.answer(input, params)?;
+        let answer = solve(variant, input, params)?;
+        (answer != expected).then_some(expected)
+    };
+    disagreement(input)?;
+
+    let items = ddmin(&granularity.split(input), |items| {
+        disagreement(&granularity.join(items)).is_some()
+    });
+    let minimized = granularity.join(&items);
+    let expected = disagreement(&minimized)?;
+    Some((minimized, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ddmin() {
+        let items: Vec<u32> = (0..100).collect();
+        let fails = |items: &[u32]| items.contains(&13) && items.contains(&71);
+        assert_eq!(ddmin(&items, fails), vec![13, 71]);
+        assert_eq!(ddmin(&items, |items| items.contains(&99)), vec![99]);
+        assert_eq!(ddmin(&[1], |_| true), vec![1]);
+    }
+
+    #[test]
+    fn test_granularity() {
+        let input = "1\n2\n\n3\n";
+        assert_eq!(Granularity::Lines.split(input), vec!["1", "2", "", "3"]);
+        assert_eq!(Granularity::Sections.split(input), vec!["1\n2", "3"]);
+        assert_eq!(Granularity::Sections.join(&["1\n2", "3"]), input);
+    }
+
+    #[test]
+    fn test_minimize() {
+        // counts the lines, except the ones containing a 7.
+        let buggy = Variant::new(1, "buggy", |input: &str| {
+            Some(input.lines().filter(|line| !line.contains('7')).count())
+        });
+        let correct = Variant::new(1, "correct", |input: &str| Some(input.lines().count()));
+        let reference = Reference::Variant(&correct);
+        let input = crate::read_file("examples", 1);
+        let params = Params::default();
+
+        let (minimized, expected) =
+            minimize(&buggy, &reference, &input, &params, Granularity::Lines).unwrap();
+        assert_eq!(minimized, "7000\n");
+        assert_eq!(expected, "1");
+
+        let (minimized, _) =
+            minimize(&buggy, &reference, &input, &params, Granularity::Sections).unwrap();
+        assert_eq!(minimized, "7000\n8000\n9000\n");
+
+        assert_eq!(
+            minimize(&correct, &reference, &input, &params, Granularity::Lines),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "spawns processes")]
+    fn test_run_command() {
+        assert_eq!(run_command("wc -l", "1\n2\n").as_deref(), Some("2"));
+        assert_eq!(run_command("exit 1", ""), None);
+    }
+}
@@ -0,0 +1,289 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Extraction of puzzle descriptions, examples and answers from the HTML of a puzzle page.
+
+const ARTICLE_START: &str = "<article class=\"day-desc\">";
+const ARTICLE_END: &str = "</article>";
+
+/// Returns the HTML of the description of each revealed part.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(ARTICLE_START) {
+        rest = &rest[start + ARTICLE_START.len()..];
+        let end = rest.find(ARTICLE_END).unwrap_or(rest.len());
+        articles.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    articles
+}
+
+/// Converts the description of the revealed parts to Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    for article in articles(html) {
+        article_to_markdown(article, &mut markdown);
+    }
+    let len = markdown.trim_end().len();
+    markdown.truncate(len);
+    markdown.push('\n');
+    markdown
+}
+
+/// Returns the content of the first code block of the description, which is
+/// the example of the puzzle.
+pub fn example(html: &str) -> Option<String> {
+    let article = *articles(html).first()?;
+    let start = article.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + article[start..].find("</code></pre>")?;
+    Some(decode_entities(&strip_tags(&article[start..end])))
+}
+
+/// Returns the highlighted answer to the example of each revealed part.
+///
+/// By convention, this is the last emphasized code of the description.
+pub fn example_answers(html: &str) -> Vec<Option<String>> {
+    articles(html)
+        .into_iter()
+        .map(|article| {
+            let candidates = [
+                ("<code><em>", "</em></code>"),
+                ("<em><code>", "</code></em>"),
+            ];
+            candidates
+                .into_iter()
+                .filter_map(|(open, close)| {
+                    let start = article.rfind(open)?;
+                    let len = article[start..].find(close)?;
+                    Some((start, &article[start + open.len()..start + len]))
+                })
+                .max_by_key(|&(start, _)| start)
+                .map(|(_, answer)| decode_entities(&strip_tags(answer)))
+        })
+        .collect()
+}
+
+/// Returns the accepted answer of each solved part, as shown below its description.
+pub fn puzzle_answers(html: &str) -> Vec<String> {
+    const PREFIX: &str = "Your puzzle answer was <code>";
+    html.match_indices(PREFIX)
+        .filter_map(|(start, _)| {
+            let rest = &html[start + PREFIX.len()..];
+            Some(decode_entities(&rest[..rest.find("</code>")?]))
+        })
+        .collect()
+}
+
+/// Parses the content of an answers file: one `<part> <answer>` per line.
+pub fn parse_answers(content: &str) -> Vec<(u8, String)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.split_once(' ')?;
+            Some((part.parse().ok()?, answer.to_string()))
+        })
+        .collect()
+}
+
+/// Formats answers to be parsed by [`parse_answers`].
+pub fn format_answers<'a>(answers: impl IntoIterator<Item = (u8, &'a str)>) -> String {
+    answers
+        .into_iter()
+        .map(|(part, answer)| format!("{part} {answer}\n"))
+        .collect()
+}
+
+fn article_to_markdown(article: &str, markdown: &mut String) {
+    let mut in_pre = false;
+    let mut links = Vec::new();
+    let mut rest = article;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(markdown, rest, in_pre);
+            break;
+        };
+        push_text(markdown, &rest[..start], in_pre);
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + len];
+        rest = &rest[start + len + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+
+        match (name, closing) {
+            ("h2", false) => markdown.push_str("## "),
+            ("h2", true) | ("p", true) => markdown.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => markdown.push('`'),
+            ("em", _) if !in_pre => markdown.push('*'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default().to_string());
+                markdown.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                let href = if href.starts_with('/') {
+                    format!("https://adventofcode.com{href}")
+                } else {
+                    href
+                };
+                markdown.push_str(&format!("]({href})"));
+            }
+            ("li", false) => markdown.push_str("- "),
+            ("li", true) | ("ul", true) => markdown.push('\n'),
+            _ => {}
+        }
+    }
+}
+
+fn push_text(markdown: &mut String, text: &str, in_pre: bool) {
+    if in_pre {
+        markdown.push_str(&decode_entities(text));
+    } else if text.trim().is_empty() && (markdown.is_empty() || markdown.ends_with('\n')) {
+        // whitespace between two blocks.
+    } else {
+        markdown.push_str(&decode_entities(&text.replace('\n', " ")));
+    }
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <a href="/2022/about">Calories</a> contained by the various meals.</p>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+4000
+</code></pre>
+<ul>
+<li>The first Elf is carrying <em>3000</em> Calories.</li>
+<li>The second Elf is carrying food with <code>4000</code> Calories.</li>
+</ul>
+<p>In the example above, this is <em><code>4000</code></em> (carried by the second Elf).</p>
+</article>
+<p>Your puzzle answer was <code>70116</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>The sum of the Calories is <code><em>7000</em></code> &amp; the Elves are &lt;happy&gt;.</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_articles() {
+        assert_eq!(articles(PAGE).len(), 2);
+        assert_eq!(articles("<html></html>").len(), 0);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE),
+            "## --- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of [Calories](https://adventofcode.com/2022/about) contained by the various meals.
+
+For example:
+
+```
+1000
+2000
+
+4000
+```
+
+- The first Elf is carrying *3000* Calories.
+- The second Elf is carrying food with `4000` Calories.
+
+In the example above, this is *`4000`* (carried by the second Elf).
+
+## --- Part Two ---
+
+The sum of the Calories is `*7000*` & the Elves are <happy>.
+"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(example(PAGE).as_deref(), Some("1000\n2000\n\n4000\n"));
+        assert_eq!(example("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_example_answers() {
+        assert_eq!(
+            example_answers(PAGE),
+            vec![Some("4000".to_string()), Some("7000".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_puzzle_answers() {
+        assert_eq!(puzzle_answers(PAGE), vec!["70116".to_string()]);
+    }
+
+    #[test]
+    fn test_answers_round_trip() {
+        let content = format_answers([(1, "24000"), (2, "45000")]);
+        assert_eq!(content, "1 24000\n2 45000\n");
+        assert_eq!(
+            parse_answers(&content),
+            vec![(1, "24000".to_string()), (2, "45000".to_string())]
+        );
+    }
+}
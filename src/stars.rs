@@ -0,0 +1,221 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Progress table of the README, generated from the submission logs.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::submissions::{self, correct_answer};
+
+const TABLE_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Returns which parts of each day have a correct answer in the submission logs of `dir`.
+pub fn solved_parts(dir: &Path) -> Vec<(u8, [bool; 2])> {
+    (1..=25)
+        .map(|day| {
+            let submissions = submissions::load(dir, day);
+            let solved = [1, 2].map(|part| correct_answer(&submissions, part).is_some());
+            (day, solved)
+        })
+        .collect()
+}
+
+/// Renders the progress table of `year`, surrounded by its markers.
+pub fn render_table(year: i32, solved: &[(u8, [bool; 2])]) -> String {
+    let stars: usize = solved
+        .iter()
+        .map(|(_, parts)| parts.iter().filter(|&&part| part).count())
+        .sum();
+    let completed = solved.iter().filter(|(_, [a, b])| *a && *b).count();
+
+    let mut table = format!("{TABLE_MARKER}\n## {year} Results\n\n");
+    table.push_str(&format!(
+        "**{stars}** / 50 ⭐ collected, **{completed}** / 25 days completed.\n\n"
+    ));
+    table.push_str("| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n");
+    for (day, parts) in solved.iter().filter(|(_, parts)| parts[0] || parts[1]) {
+        let [a, b] = parts.map(|solved| if solved { "⭐" } else { " " });
+        table.push_str(&format!(
+            "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {a} | {b} |\n"
+        ));
+    }
+    table.push_str(TABLE_MARKER);
+    table
+}
+
+/// Returns the year of the table of `readme`, if any.
+pub fn table_year(readme: &str) -> Option<i32> {
+    let start = readme.find(TABLE_MARKER)? + TABLE_MARKER.len();
+    let header = readme[start..].trim_start().strip_prefix("## ")?;
+    header.split_once(" Results")?.0.parse().ok()
+}
+
+/// Returns which parts of each day have a star in the table of `readme`.
+pub fn table_rows(readme: &str) -> Vec<(u8, [bool; 2])> {
+    let Some(start) = readme.find(TABLE_MARKER) else {
+        return Vec::new();
+    };
+    let table = &readme[start + TABLE_MARKER.len()..];
+    let table = &table[..table.find(TABLE_MARKER).unwrap_or(table.len())];
+    table
+        .lines()
+        .filter_map(|line| {
+            let mut cells = line.split('|').skip(1);
+            let day = cells.next()?.trim().strip_prefix("[Day ")?;
+            let day = day[..day.find(']')?].parse().ok()?;
+            let part_one = cells.next()?.contains('⭐');
+            let part_two = cells.next()?.contains('⭐');
+            Some((day, [part_one, part_two]))
+        })
+        .collect()
+}
+
+/// Replaces the table of `readme` with `table`.
+///
+/// Returns `None` if `readme` has no table markers.
+pub fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(TABLE_MARKER)?;
+    let end = start
+        + TABLE_MARKER.len()
+        + readme[start + TABLE_MARKER.len()..].find(TABLE_MARKER)?
+        + TABLE_MARKER.len();
+    Some(format!("{}{table}{}", &readme[..start], &readme[end..]))
+}
+
+/// Regenerates the table of the README at `readme_path` from the logs of `submissions_dir`.
+///
+/// The year of the existing table is kept unless `year` is given. The stars of the
+/// existing table of that year are kept too, as days solved before the logs have none.
+pub fn update_readme(
+    readme_path: &Path,
+    submissions_dir: &Path,
+    year: Option<i32>,
+) -> io::Result<usize> {
+    let readme = fs::read_to_string(readme_path)?;
+    let year = year
+        .or_else(|| table_year(&readme))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unknown year"))?;
+    let kept = match table_year(&readme) {
+        Some(table_year) if table_year == year => table_rows(&readme),
+        _ => Vec::new(),
+    };
+    let mut solved = solved_parts(submissions_dir);
+    for (day, parts) in &mut solved {
+        if let Some((_, kept)) = kept.iter().find(|(kept_day, _)| kept_day == day) {
+            parts[0] |= kept[0];
+            parts[1] |= kept[1];
+        }
+    }
+    let readme = replace_table(&readme, &render_table(year, &solved)).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("missing \"{TABLE_MARKER}\" markers"),
+        )
+    })?;
+    fs::write(readme_path, readme)?;
+    Ok(solved
+        .iter()
+        .map(|(_, parts)| parts.iter().filter(|&&part| part).count())
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "# Title
+
+<!--- advent_readme_stars table --->
+## 2022 Results
+
+| Day | Part 1 | Part 2 |
+| :---: | :---: | :---: |
+| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |
+<!--- advent_readme_stars table --->
+
+Footer
+";
+
+    #[test]
+    fn test_table_year() {
+        assert_eq!(table_year(README), Some(2022));
+        assert_eq!(table_year("# Title"), None);
+    }
+
+    #[test]
+    fn test_render_table() {
+        let mut solved: Vec<_> = (1..=25).map(|day| (day, [false, false])).collect();
+        solved[0].1 = [true, true];
+        solved[2].1 = [true, false];
+        assert_eq!(
+            render_table(2022, &solved),
+            "<!--- advent_readme_stars table --->
+## 2022 Results
+
+**3** / 50 ⭐ collected, **1** / 25 days completed.
+
+| Day | Part 1 | Part 2 |
+| :---: | :---: | :---: |
+| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |
+| [Day 3](https://adventofcode.com/2022/day/3) | ⭐ |   |
+<!--- advent_readme_stars table --->"
+        );
+    }
+
+    #[test]
+    fn test_table_rows() {
+        assert_eq!(table_rows(README), vec![(1, [true, true])]);
+        let table = render_table(2022, &[(3, [true, false]), (12, [false, true])]);
+        assert_eq!(
+            table_rows(&table),
+            vec![(3, [true, false]), (12, [false, true])]
+        );
+        assert_eq!(table_rows("# Title"), vec![]);
+    }
+
+    #[test]
+    fn test_update_readme_keeps_rows_without_logs() {
+        let dir = std::env::temp_dir().join(format!("adventofcode-stars-{}", std::process::id()));
+        let submissions_dir = dir.join("submissions");
+        fs::create_dir_all(&submissions_dir).unwrap();
+        let readme_path = dir.join("README.md");
+        fs::write(&readme_path, README).unwrap();
+
+        assert_eq!(
+            update_readme(&readme_path, &submissions_dir, None).unwrap(),
+            2
+        );
+        let readme = fs::read_to_string(&readme_path).unwrap();
+        assert!(readme.contains("| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |"));
+
+        fs::write(submissions::log_path(&submissions_dir, 2), "1 correct 42\n").unwrap();
+        assert_eq!(
+            update_readme(&readme_path, &submissions_dir, None).unwrap(),
+            3
+        );
+        let readme = fs::read_to_string(&readme_path).unwrap();
+        assert_eq!(
+            table_rows(&readme),
+            vec![(1, [true, true]), (2, [true, false])]
+        );
+
+        // the stars of another year are not kept.
+        assert_eq!(
+            update_readme(&readme_path, &submissions_dir, Some(2021)).unwrap(),
+            1
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_replace_table() {
+        assert_eq!(
+            replace_table(README, "<!--- advent_readme_stars table --->\nnew table\n<!--- advent_readme_stars table --->").as_deref(),
+            Some("# Title\n\n<!--- advent_readme_stars table --->\nnew table\n<!--- advent_readme_stars table --->\n\nFooter\n")
+        );
+        assert_eq!(replace_table("# Title", "table"), None);
+    }
+}
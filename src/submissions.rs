@@ -0,0 +1,244 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Local log of the answers submitted to adventofcode.com.
+//!
+//! Each line of a log is a submission: `<part> <verdict> <answer>`.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Reads the verdict from the response of adventofcode.com to a submission.
+    ///
+    /// Returns `None` when the answer was not evaluated, e.g. when submitting too soon.
+    pub fn from_response(response: &str) -> Option<Verdict> {
+        if response.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if response.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if response.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else {
+            None
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            _ => Err(format!("unknown verdict \"{s}\"")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.part, self.verdict, self.answer)
+    }
+}
+
+/// Parses a log, ignoring malformed lines.
+pub fn parse_log(content: &str) -> Vec<Submission> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ' ');
+            Some(Submission {
+                part: fields.next()?.parse().ok()?,
+                verdict: fields.next()?.parse().ok()?,
+                answer: fields.next()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Path of the log of `day` in `dir`.
+pub fn log_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("{day:02}.txt"))
+}
+
+/// Reads the log of `day` in `dir`, which is empty if there is no log yet.
+pub fn load(dir: &Path, day: u8) -> Vec<Submission> {
+    parse_log(&fs::read_to_string(log_path(dir, day)).unwrap_or_default())
+}
+
+/// Appends `submission` to the log of `day` in `dir`.
+pub fn record(dir: &Path, day: u8, submission: &Submission) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path(dir, day))?;
+    writeln!(file, "{submission}")
+}
+
+/// Returns the accepted answer of `part`, if any.
+pub fn correct_answer(submissions: &[Submission], part: u8) -> Option<&str> {
+    submissions
+        .iter()
+        .find(|s| s.part == part && s.verdict == Verdict::Correct)
+        .map(|s| s.answer.as_str())
+}
+
+/// Reasons for which an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong,
+    TooHigh(String),
+    TooLow(String),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part is already solved, the answer was {answer}")
+            }
+            Refusal::KnownWrong => write!(f, "this answer was already submitted and is wrong"),
+            Refusal::TooHigh(bound) => write!(f, "{bound} was already too high"),
+            Refusal::TooLow(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+/// Checks `answer` against the previous submissions of `part`.
+pub fn check(submissions: &[Submission], part: u8, answer: &str) -> Result<(), Refusal> {
+    if let Some(correct) = correct_answer(submissions, part) {
+        return Err(Refusal::AlreadySolved(correct.to_string()));
+    }
+
+    let previous = submissions.iter().filter(|s| s.part == part);
+    if previous.clone().any(|s| s.answer == answer) {
+        return Err(Refusal::KnownWrong);
+    }
+
+    let Ok(value) = answer.parse::<i64>() else {
+        return Ok(());
+    };
+    let bounds = previous.filter_map(|s| Some((s.verdict, s.answer.parse::<i64>().ok()?)));
+    for (verdict, bound) in bounds {
+        match verdict {
+            Verdict::TooHigh if value >= bound => return Err(Refusal::TooHigh(bound.to_string())),
+            Verdict::TooLow if value <= bound => return Err(Refusal::TooLow(bound.to_string())),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOG: &str = "1 too_low 100\n1 too_high 200\n1 wrong 150\n2 correct 42\n";
+
+    #[test]
+    fn test_from_response() {
+        assert_eq!(
+            Verdict::from_response(
+                "That's the right answer! You are one gold star closer to saving your vacation."
+            ),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "That's not the right answer; your answer is too high. If you're stuck, ..."
+            ),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer. If you're stuck, ..."),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently; you have to wait after submitting an answer before trying again."),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_log() {
+        let submissions = parse_log(LOG);
+        assert_eq!(submissions.len(), 4);
+        assert_eq!(
+            submissions[1],
+            Submission {
+                part: 1,
+                verdict: Verdict::TooHigh,
+                answer: "200".to_string()
+            }
+        );
+        assert_eq!(submissions[1].to_string(), "1 too_high 200");
+        assert_eq!(correct_answer(&submissions, 1), None);
+        assert_eq!(correct_answer(&submissions, 2), Some("42"));
+    }
+
+    #[test]
+    fn test_check() {
+        let submissions = parse_log(LOG);
+        assert_eq!(check(&submissions, 1, "120"), Ok(()));
+        assert_eq!(check(&submissions, 1, "150"), Err(Refusal::KnownWrong));
+        assert_eq!(
+            check(&submissions, 1, "250"),
+            Err(Refusal::TooHigh("200".to_string()))
+        );
+        assert_eq!(
+            check(&submissions, 1, "-5"),
+            Err(Refusal::TooLow("100".to_string()))
+        );
+        assert_eq!(check(&submissions, 1, "ABC"), Ok(()));
+        assert_eq!(
+            check(&submissions, 2, "43"),
+            Err(Refusal::AlreadySolved("42".to_string()))
+        );
+    }
+}
@@ -0,0 +1,367 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Named implementations of the parts of a day, run side by side by [`run`].
+
+use std::collections::HashMap;
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Answer to a part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => n.fmt(f),
+            Answer::Text(text) => text.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i64::try_from(n) {
+                    Ok(n) => Answer::Number(n),
+                    Err(_) => Answer::Text(n.to_string()),
+                }
+            }
+        })*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+/// Named parameters of the parts, such as the line checked by part one of day 15.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(HashMap<String, String>);
+
+impl Params {
+    /// Value of the parameter `name`, or `default` if it is not set.
+    ///
+    /// Panics if the value cannot be parsed, like solutions do on invalid inputs.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> T {
+        match self.0.get(name) {
+            Some(value) => value
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid value of parameter {name}: \"{value}\"")),
+            None => default,
+        }
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Params(
+            iter.into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+type Solver = Box<dyn Fn(&str, &Params) -> Option<Answer> + Send + Sync>;
+
+/// An implementation of a part.
+pub struct Variant {
+    pub part: u8,
+    pub name: &'static str,
+    solver: Solver,
+}
+
+impl Variant {
+    pub fn new<T, F>(part: u8, name: &'static str, solver: F) -> Variant
+    where
+        T: Into<Answer>,
+        F: Fn(&str) -> Option<T> + Send + Sync + 'static,
+    {
+        Variant::with_params(part, name, move |input, _| solver(input))
+    }
+
+    /// A variant whose solver takes [`Params`], which are empty unless given to
+    /// [`Variant::solve_with`].
+    pub fn with_params<T, F>(part: u8, name: &'static str, solver: F) -> Variant
+    where
+        T: Into<Answer>,
+        F: Fn(&str, &Params) -> Option<T> + Send + Sync + 'static,
+    {
+        Variant {
+            part,
+            name,
+            solver: Box::new(move |input, params| solver(input, params).map(Into::into)),
+        }
+    }
+
+    pub fn solve(&self, input: &str) -> Option<Answer> {
+        self.solve_with(input, &Params::default())
+    }
+
+    pub fn solve_with(&self, input: &str, params: &Params) -> Option<Answer> {
+        (self.solver)(input, params)
+    }
+
+    /// Solves `input`, returning the answer and the time it took.
+    pub fn timed(&self, input: &str) -> (Option<Answer>, Duration) {
+        self.timed_with(input, &Params::default())
+    }
+
+    pub fn timed_with(&self, input: &str, params: &Params) -> (Option<Answer>, Duration) {
+        let timer = Instant::now();
+        let answer = self.solve_with(input, params);
+        (answer, timer.elapsed())
+    }
+}
+
+impl fmt::Debug for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Variant")
+            .field("part", &self.part)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Outcome of the variants of a part.
+#[derive(Debug)]
+pub struct PartRun<'a> {
+    pub part: u8,
+    pub results: Vec<(&'a Variant, Option<Answer>, Duration)>,
+}
+
+impl PartRun<'_> {
+    /// The answer of the first variant, which is the reference implementation.
+    pub fn answer(&self) -> Option<&Answer> {
+        self.results.first()?.1.as_ref()
+    }
+
+    /// Variants whose answer differs from the one of the first variant.
+    pub fn disagreements(&self) -> impl Iterator<Item = &(&Variant, Option<Answer>, Duration)> {
+        let answer = self.answer();
+        self.results
+            .iter()
+            .skip(1)
+            .filter(move |(_, other, _)| other.as_ref() != answer)
+    }
+}
+
+/// Runs every variant on `input`, grouped by part in order of registration.
+pub fn run_all<'a>(variants: &'a [Variant], input: &str) -> Vec<PartRun<'a>> {
+    let mut runs: Vec<PartRun> = Vec::new();
+    for variant in variants {
+        let (answer, elapsed) = variant.timed(input);
+        match runs.iter_mut().find(|run| run.part == variant.part) {
+            Some(run) => run.results.push((variant, answer, elapsed)),
+            None => runs.push(PartRun {
+                part: variant.part,
+                results: vec![(variant, answer, elapsed)],
+            }),
+        }
+    }
+    runs
+}
+
+/// Renders a part the same way as [`solve!`](crate::solve), followed by a
+/// comparison of the variants if there are several.
+pub fn render(run: &PartRun) -> String {
+    let mut output = format!("🎄 {ANSI_BOLD}Part {}{ANSI_RESET} 🎄\n", run.part);
+    let Some((_, answer, elapsed)) = run.results.first() else {
+        return output;
+    };
+    match answer {
+        Some(answer) => output.push_str(&format!(
+            "{answer} {ANSI_ITALIC}(elapsed: {elapsed:.2?}){ANSI_RESET}\n"
+        )),
+        None => output.push_str("not solved.\n"),
+    }
+    if run.results.len() == 1 {
+        return output;
+    }
+
+    let width = run
+        .results
+        .iter()
+        .map(|(variant, _, _)| variant.name.len())
+        .max()
+        .unwrap_or_default();
+    let fastest = run
+        .results
+        .iter()
+        .map(|&(_, _, elapsed)| elapsed)
+        .min()
+        .unwrap_or_default();
+    for (variant, other, elapsed) in &run.results {
+        let name = variant.name;
+        let ratio = elapsed.as_secs_f64() / fastest.as_secs_f64().max(f64::EPSILON);
+        let time = format!("{elapsed:.2?}");
+        output.push_str(&format!(
+            "  {name:<width$}  {time:>10}  {ANSI_ITALIC}x{ratio:.2}{ANSI_RESET}"
+        ));
+        if other.as_ref() != answer.as_ref() {
+            let other = other
+                .as_ref()
+                .map_or("not solved".to_string(), Answer::to_string);
+            output.push_str(&format!("  ❌ {other}"));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Runs and prints every variant on `input`, exiting with an error if some disagree.
+pub fn run(variants: &[Variant], input: &str) {
+    let runs = run_all(variants, input);
+    for run in &runs {
+        print!("{}", render(run));
+    }
+    let disagreements: Vec<_> = runs
+        .iter()
+        .flat_map(|run| {
+            run.disagreements()
+                .map(move |(variant, _, _)| (run.part, variant.name))
+        })
+        .collect();
+    if !disagreements.is_empty() {
+        for (part, name) in disagreements {
+            eprintln!("Variant {name} of part {part} disagrees with the first variant.");
+        }
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variants() -> Vec<Variant> {
+        vec![
+            Variant::new(1, "sum", |input: &str| Some(input.len() as u32)),
+            Variant::new(1, "fold", |input: &str| {
+                Some(input.bytes().fold(0usize, |acc, _| acc + 1))
+            }),
+            Variant::new(1, "wrong", |_: &str| Some(0)),
+            Variant::new(2, "text", |input: &str| Some(input.to_uppercase())),
+        ]
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(42u32), Answer::Number(42));
+        assert_eq!(Answer::from(-1i32), Answer::Number(-1));
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from("CMZ".to_string()).to_string(), "CMZ");
+    }
+
+    #[test]
+    fn test_params() {
+        let variant = Variant::with_params(1, "line", |input: &str, params: &Params| {
+            Some(input.len() * params.get("times", 1))
+        });
+        assert_eq!(variant.solve("abc"), Some(Answer::Number(3)));
+        let params = Params::from_iter([("times", "2")]);
+        assert_eq!(variant.solve_with("abc", &params), Some(Answer::Number(6)));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value of parameter times")]
+    fn test_invalid_param() {
+        Params::from_iter([("times", "x")]).get("times", 1);
+    }
+
+    #[test]
+    fn test_run_all() {
+        let variants = variants();
+        let runs = run_all(&variants, "abc");
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].part, 1);
+        assert_eq!(runs[0].answer(), Some(&Answer::Number(3)));
+        let disagreements: Vec<_> = runs[0]
+            .disagreements()
+            .map(|(variant, _, _)| variant.name)
+            .collect();
+        assert_eq!(disagreements, vec!["wrong"]);
+        assert_eq!(runs[1].answer(), Some(&Answer::Text("ABC".to_string())));
+        assert_eq!(runs[1].disagreements().count(), 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let variants = variants();
+        let runs = run_all(&variants, "abc");
+
+        let part_two = render(&runs[1]);
+        assert_eq!(crate::parse_answer(&part_two, 2).as_deref(), Some("ABC"));
+        assert_eq!(part_two.lines().count(), 2);
+
+        let part_one = render(&runs[0]);
+        assert_eq!(crate::parse_answer(&part_one, 1).as_deref(), Some("3"));
+        assert_eq!(part_one.matches("elapsed:").count(), 1);
+        assert!(part_one.contains("  wrong "));
+        assert!(part_one.trim_end().ends_with("❌ 0"));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "insta is unsupported")]
+    fn test_render_snapshot() {
+        let variants = variants();
+        let results = variants[..3]
+            .iter()
+            .zip([3, 3, 0])
+            .zip([120, 80, 2000])
+            .map(|((variant, answer), micros)| {
+                (
+                    variant,
+                    Some(Answer::Number(answer)),
+                    Duration::from_micros(micros),
+                )
+            })
+            .collect();
+        let output = render(&PartRun { part: 1, results });
+        let plain = [ANSI_BOLD, ANSI_ITALIC, ANSI_RESET]
+            .iter()
+            .fold(output, |output, code| output.replace(code, ""));
+        insta::assert_snapshot!(plain);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "too slow")]
+    fn test_days_agree_on_examples() {
+        for day in 1..=25 {
+            let Some(variants) = crate::variants(day) else {
+                continue;
+            };
+            let input = crate::read_file("examples", day);
+            for run in run_all(&variants, &input) {
+                let disagreements: Vec<_> = run
+                    .disagreements()
+                    .map(|(variant, _, _)| variant.name)
+                    .collect();
+                assert!(
+                    disagreements.is_empty(),
+                    "day {day} part {}: {disagreements:?}",
+                    run.part
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Checks every variant against the examples that have an answers file next to them,
+//! such as the inputs shrunk by `cargo minimize`.
+
+use std::fs;
+
+use adventofcode::config::config;
+use adventofcode::puzzle;
+
+#[test]
+fn test_examples_with_answers() {
+    let mut failures = Vec::new();
+    for entry in fs::read_dir(config().dir("examples")).unwrap() {
+        let path = entry.unwrap().path();
+        if path
+            .extension()
+            .is_none_or(|extension| extension != "answers")
+        {
+            continue;
+        }
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let Some(variants) = name
+            .get(..2)
+            .and_then(|day| adventofcode::variants(day.parse().ok()?))
+        else {
+            continue;
+        };
+        let input = fs::read_to_string(path.with_extension("txt")).unwrap();
+
+        for (part, expected) in puzzle::parse_answers(&fs::read_to_string(&path).unwrap()) {
+            for variant in variants.iter().filter(|variant| variant.part == part) {
+                let answer = variant.solve(&input).map(|answer| answer.to_string());
+                if answer.as_deref() != Some(expected.as_str()) {
+                    failures.push(format!(
+                        "{name}: {} answered {answer:?} instead of {expected}",
+                        variant.name
+                    ));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
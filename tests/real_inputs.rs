@@ -0,0 +1,77 @@
+#![cfg(feature = "real-inputs")]
+//! Regression tests of every day and part against the puzzle inputs and the answers
+//! accepted in `submissions/`, run with `cargo test --features real-inputs`.
+//!
+//! Tests pass with a note when the input is missing, but fail when a day answers its
+//! input without an accepted answer to check it against.
+
+use std::path::Path;
+
+use adventofcode::submissions;
+use adventofcode::variant::run_all;
+
+fn check(day: u8, part: u8) {
+    let Some(variants) = adventofcode::variants(day) else {
+        return;
+    };
+    let variants: Vec<_> = variants
+        .into_iter()
+        .filter(|variant| variant.part == part)
+        .collect();
+    let Some(input) = adventofcode::try_read_file("inputs", day) else {
+        eprintln!("skipped: no input for day {day}.");
+        return;
+    };
+    let runs = run_all(&variants, &input);
+    let Some(run) = runs.first() else {
+        return;
+    };
+    let submissions = submissions::load(Path::new("submissions"), day);
+    let expected = submissions::correct_answer(&submissions, part);
+    for (variant, answer, _) in &run.results {
+        let Some(answer) = answer.as_ref().map(ToString::to_string) else {
+            eprintln!("skipped: {} of day {day} is not solved.", variant.name);
+            continue;
+        };
+        // the letters drawn by multi-line answers are read by a human.
+        if answer.contains('\n') {
+            eprintln!("skipped: {} of day {day} draws its answer.", variant.name);
+            continue;
+        }
+        let Some(expected) = expected else {
+            panic!(
+                "no accepted answer for day {day} part {part}, add `{part} correct ANSWER` to \"{}\".",
+                submissions::log_path(Path::new("submissions"), day).display()
+            );
+        };
+        assert_eq!(
+            answer, expected,
+            "{} of day {day} part {part}",
+            variant.name
+        );
+    }
+}
+
+macro_rules! real_input_tests {
+    ($($day:ident: $n:literal),* $(,)?) => {$(
+        mod $day {
+            #[test]
+            fn part_one() {
+                super::check($n, 1);
+            }
+
+            #[test]
+            fn part_two() {
+                super::check($n, 2);
+            }
+        }
+    )*};
+}
+
+real_input_tests! {
+    day01: 1, day02: 2, day03: 3, day04: 4, day05: 5,
+    day06: 6, day07: 7, day08: 8, day09: 9, day10: 10,
+    day11: 11, day12: 12, day13: 13, day14: 14, day15: 15,
+    day16: 16, day17: 17, day18: 18, day19: 19, day20: 20,
+    day21: 21, day22: 22, day23: 23, day24: 24, day25: 25,
+}
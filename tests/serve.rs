@@ -0,0 +1,108 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+
+use serde_json::Value;
+
+/// A running `serve`, killed when dropped.
+struct Server {
+    child: Child,
+    address: String,
+}
+
+impl Server {
+    fn start() -> Server {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_serve"))
+            .args(["--port", "0"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let address = line.trim().rsplit(' ').next().unwrap().to_string();
+        Server { child, address }
+    }
+
+    fn post(&self, path: &str, input: &str) -> (u16, Value) {
+        let response = match ureq::post(&format!("{}{path}", self.address)).send_string(input) {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => panic!("request failed: {e}"),
+        };
+        let status = response.status();
+        (
+            status,
+            serde_json::from_str(&response.into_string().unwrap()).unwrap(),
+        )
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn example(day: u8) -> String {
+    std::fs::read_to_string(format!("src/examples/{day:02}.txt")).unwrap()
+}
+
+#[test]
+fn test_solve() {
+    let server = Server::start();
+
+    let (status, body) = server.post("/solve/1/1", &example(1));
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], 24000);
+    assert_eq!(body["variant"], "part_one");
+    assert!(body["elapsed"].as_f64().unwrap() >= 0.0);
+
+    let (_, body) = server.post("/solve/5/2", &example(5));
+    assert_eq!(body["answer"], "MCD");
+
+    let (_, body) = server.post("/solve/16/2", &example(16));
+    assert_eq!(body["answer"], Value::Null);
+}
+
+#[test]
+fn test_params() {
+    let server = Server::start();
+
+    let (_, body) = server.post("/solve/15/1?target_line=10", &example(15));
+    assert_eq!(body["answer"], 26);
+    let (_, body) = server.post("/solve/15/2?max_coordinate=20", &example(15));
+    assert_eq!(body["answer"], 56000011);
+
+    let (_, body) = server.post("/solve/2/1?variant=part_one_2", &example(2));
+    assert_eq!(
+        (&body["variant"], &body["answer"]),
+        (&"part_one_2".into(), &15.into())
+    );
+}
+
+#[test]
+fn test_errors() {
+    let server = Server::start();
+
+    let get = ureq::get(&format!("{}/solve/1/1", server.address)).call();
+    assert!(matches!(get, Err(ureq::Error::Status(405, _))));
+    assert_eq!(server.post("/solve/25/1", "").0, 404);
+    assert_eq!(server.post("/solve/1/3", "").0, 404);
+    assert_eq!(server.post("/solve/one/1", "").0, 404);
+
+    let (status, body) = server.post("/solve/15/1?target_line=x", &example(15));
+    assert_eq!(status, 422);
+    assert!(body["error"].as_str().unwrap().contains("target_line"));
+
+    // the server survives panicking solutions.
+    assert_eq!(server.post("/solve/18/1", "1,2").0, 422);
+    let monkeys = example(11).replace("throw to monkey 3", "throw to monkey 300000");
+    let (status, body) = server.post("/solve/11/1", &monkeys);
+    assert_eq!(status, 422);
+    assert!(body["error"].as_str().unwrap().contains("300000"));
+    assert_eq!(server.post("/solve/13/1", &"[".repeat(200_000)).0, 422);
+    assert_eq!(server.post("/solve/1/1", &example(1)).0, 200);
+}
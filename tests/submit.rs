@@ -0,0 +1,120 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// A stub of aoc-cli which logs its arguments and answers `$AOC_STUB_RESPONSE`.
+const AOC_STUB: &str = r#"#!/bin/sh
+if [ "$1" = "-V" ]; then
+    echo "aoc-cli stub"
+    exit 0
+fi
+echo "$@" >> calls.log
+echo "$AOC_STUB_RESPONSE"
+"#;
+
+fn setup(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("adventofcode-submit-{name}"));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("bin")).unwrap();
+    let stub = dir.join("bin").join("aoc");
+    fs::write(&stub, AOC_STUB).unwrap();
+    fs::set_permissions(&stub, fs::Permissions::from_mode(0o755)).unwrap();
+    dir
+}
+
+fn submit(dir: &Path, args: &[&str], response: &str) -> Output {
+    let path = format!(
+        "{}:{}",
+        dir.join("bin").display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    Command::new(env!("CARGO_BIN_EXE_submit"))
+        .args(args)
+        .current_dir(dir)
+        .env("PATH", path)
+        .env("AOC_STUB_RESPONSE", response)
+        .output()
+        .unwrap()
+}
+
+fn calls(dir: &Path) -> String {
+    fs::read_to_string(dir.join("calls.log")).unwrap_or_default()
+}
+
+fn log(dir: &Path, day: &str) -> String {
+    fs::read_to_string(dir.join("submissions").join(format!("{day}.txt"))).unwrap_or_default()
+}
+
+#[test]
+fn test_submit_correct_answer() {
+    let dir = setup("correct");
+    fs::write(
+        dir.join("README.md"),
+        "<!--- advent_readme_stars table --->\n## 2022 Results\n<!--- advent_readme_stars table --->\n",
+    )
+    .unwrap();
+    let output = submit(
+        &dir,
+        &["3", "1", "--answer", "157"],
+        "That's the right answer! You are one gold star closer to saving your vacation.",
+    );
+    assert!(output.status.success());
+    assert_eq!(calls(&dir), "--day 3 submit 1 157\n");
+    assert_eq!(log(&dir, "03"), "1 correct 157\n");
+    let readme = fs::read_to_string(dir.join("README.md")).unwrap();
+    assert!(readme.contains("| [Day 3](https://adventofcode.com/2022/day/3) | ⭐ |   |"));
+
+    let output = submit(&dir, &["3", "1", "--answer", "158"], "");
+    assert!(!output.status.success());
+    assert_eq!(calls(&dir).lines().count(), 1);
+}
+
+#[test]
+fn test_submit_refuses_known_wrong_answers() {
+    let dir = setup("wrong");
+    let output = submit(
+        &dir,
+        &["5", "2", "--answer", "MCD", "--year", "2022"],
+        "That's not the right answer. If you're stuck, make sure you're using the full input data.",
+    );
+    assert!(!output.status.success());
+    assert_eq!(calls(&dir), "--year 2022 --day 5 submit 2 MCD\n");
+    assert_eq!(log(&dir, "05"), "2 wrong MCD\n");
+
+    let output = submit(&dir, &["5", "2", "--answer", "MCD"], "");
+    assert!(!output.status.success());
+    assert_eq!(calls(&dir).lines().count(), 1);
+}
+
+#[test]
+fn test_submit_refuses_out_of_bounds_answers() {
+    let dir = setup("bounds");
+    submit(
+        &dir,
+        &["7", "1", "--answer", "1000"],
+        "That's not the right answer; your answer is too high.",
+    );
+    submit(
+        &dir,
+        &["7", "1", "--answer", "10"],
+        "That's not the right answer; your answer is too low.",
+    );
+    assert_eq!(log(&dir, "07"), "1 too_high 1000\n1 too_low 10\n");
+
+    for answer in ["1200", "1000", "10", "3"] {
+        let output = submit(&dir, &["7", "1", "--answer", answer], "");
+        assert!(!output.status.success());
+    }
+    assert_eq!(calls(&dir).lines().count(), 2);
+
+    submit(
+        &dir,
+        &["7", "1", "--answer", "500"],
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.",
+    );
+    assert_eq!(calls(&dir).lines().count(), 3);
+    assert_eq!(log(&dir, "07"), "1 too_high 1000\n1 too_low 10\n");
+}
@@ -3,6 +3,11 @@ import os
 import sys
 import webbrowser
 
+try:
+    import tomllib
+except ImportError:  # python < 3.11
+    tomllib = None
+
 
 def run(command):
     print(">", command)
@@ -19,12 +24,23 @@ def get_day():
     return datetime.datetime.today().day
 
 
+def get_year():
+    if tomllib is not None and os.path.exists("aoc.toml"):
+        with open("aoc.toml", "rb") as f:
+            year = tomllib.load(f).get("year")
+        if year is not None:
+            return year
+    today = datetime.datetime.today()
+    return today.year if today.month == 12 else today.year - 1
+
+
 def main():
     day = get_day()
+    year = get_year()
     run(f"cargo scaffold {day}")
     run(f"cargo download {day}")
     run("code .")
-    webbrowser.open(f"https://adventofcode.com/2022/day/{day}")
+    webbrowser.open(f"https://adventofcode.com/{year}/day/{day}")
 
 
 if __name__ == "__main__":